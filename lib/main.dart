@@ -510,6 +510,13 @@ class _ReceiveTabState extends State<ReceiveTab> {
                           '请等待文件下载完成...',
                           style: Theme.of(context).textTheme.bodySmall,
                         ),
+                        const SizedBox(height: 8),
+                        TextButton.icon(
+                          onPressed: () =>
+                              provider.cancelReceive(keepPartial: true),
+                          icon: const Icon(Icons.cancel),
+                          label: const Text('取消'),
+                        ),
                       ],
                     ),
                   ),
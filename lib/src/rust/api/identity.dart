@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../entry_names.dart';
+import '../error.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            Future<String>  initIdentity({required String configDir }) => RustLib.instance.api.crateApiIdentityInitIdentity(configDir: configDir);
+
+String  nodeId() => RustLib.instance.api.crateApiIdentityNodeId();
+
+Future<String>  rotateIdentity() => RustLib.instance.api.crateApiIdentityRotateIdentity();
+
+String  exportIdentity() => RustLib.instance.api.crateApiIdentityExportIdentity();
+
+Future<String>  importIdentity({required String secret }) => RustLib.instance.api.crateApiIdentityImportIdentity(secret: secret);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../entry_names.dart';
+import '../error.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            Future<String>  initNode({required NodeOptions options }) => RustLib.instance.api.crateApiNodeInitNode(options: options);
+
+Future<void>  shutdownNode() => RustLib.instance.api.crateApiNodeShutdownNode();
+
+            
+            
\ No newline at end of file
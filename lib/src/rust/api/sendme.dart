@@ -3,17 +3,56 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../entry_names.dart';
+import '../error.dart';
 import '../frb_generated.dart';
 import '../lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-void initLogging() => RustLib.instance.api.crateApiSendmeInitLogging();
 
-Future<SendResult> sendFile({required String path}) =>
-    RustLib.instance.api.crateApiSendmeSendFile(path: path);
+            
 
-Future<ReceiveResult> receiveFile({required String ticket}) =>
-    RustLib.instance.api.crateApiSendmeReceiveFile(ticket: ticket);
+            void  initLogging() => RustLib.instance.api.crateApiSendmeInitLogging();
 
-String formatBytes({required BigInt size}) =>
-    RustLib.instance.api.crateApiSendmeFormatBytes(size: size);
+Stream<ProgressInfo>  progressStream() => RustLib.instance.api.crateApiSendmeProgressStream();
+
+Stream<SendEvent>  sendEventStream() => RustLib.instance.api.crateApiSendmeSendEventStream();
+
+EndpointConfig  defaultEndpointConfig() => RustLib.instance.api.crateApiSendmeDefaultEndpointConfig();
+
+EndpointConfig  lanOnlyEndpointConfig() => RustLib.instance.api.crateApiSendmeLanOnlyEndpointConfig();
+
+QrOptions  defaultQrOptions() => RustLib.instance.api.crateApiSendmeDefaultQrOptions();
+
+void  setLocale({required MessageLocale locale }) => RustLib.instance.api.crateApiSendmeSetLocale(locale: locale);
+
+Future<SendResult>  sendFile({required String path , required SendOptions options }) => RustLib.instance.api.crateApiSendmeSendFile(path: path, options: options);
+
+Future<SendResult>  sendFiles({required List<String> paths , required SendOptions options }) => RustLib.instance.api.crateApiSendmeSendFiles(paths: paths, options: options);
+
+Future<StopSharingResult>  stopSharing({required String ticketOrId }) => RustLib.instance.api.crateApiSendmeStopSharing(ticketOrId: ticketOrId);
+
+BigInt  createReceive() => RustLib.instance.api.crateApiSendmeCreateReceive();
+
+Future<ReceiveResult>  receiveFile({required BigInt transferId , required String ticket , required ReceiveOptions options }) => RustLib.instance.api.crateApiSendmeReceiveFile(transferId: transferId, ticket: ticket, options: options);
+
+Future<void>  cancelTransfer({required BigInt id , required bool keepPartial }) => RustLib.instance.api.crateApiSendmeCancelTransfer(id: id, keepPartial: keepPartial);
+
+Future<List<PartialDownload>>  listPartialDownloads({String? tempDir }) => RustLib.instance.api.crateApiSendmeListPartialDownloads(tempDir: tempDir);
+
+Future<void>  deletePartialDownload({required String hash , String? tempDir }) => RustLib.instance.api.crateApiSendmeDeletePartialDownload(hash: hash, tempDir: tempDir);
+
+TransferInfo?  getTransfer({required BigInt id }) => RustLib.instance.api.crateApiSendmeGetTransfer(id: id);
+
+void  forgetTransfer({required BigInt id }) => RustLib.instance.api.crateApiSendmeForgetTransfer(id: id);
+
+List<TransferInfo>  listTransfers() => RustLib.instance.api.crateApiSendmeListTransfers();
+
+TicketInfo  parseTicket({required String ticket }) => RustLib.instance.api.crateApiSendmeParseTicket(ticket: ticket);
+
+QrImage  qrCode({required String data , required QrOptions options }) => RustLib.instance.api.crateApiSendmeQrCode(data: data, options: options);
+
+String  formatBytes({required BigInt size }) => RustLib.instance.api.crateApiSendmeFormatBytes(size: size);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../entry_names.dart';
+import '../error.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            void  setCodeResolver({required CodeResolverConfig config }) => RustLib.instance.api.crateApiShortCodeSetCodeResolver(config: config);
+
+Future<String>  publishShortCode({required BigInt transferId }) => RustLib.instance.api.crateApiShortCodePublishShortCode(transferId: transferId);
+
+Future<String>  resolveShortCode({required String code }) => RustLib.instance.api.crateApiShortCodeResolveShortCode(code: code);
+
+            
+            
\ No newline at end of file
@@ -6,5 +6,10 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-String greet({required String name}) =>
-    RustLib.instance.api.crateApiSimpleGreet(name: name);
+
+            
+
+            String  greet({required String name }) => RustLib.instance.api.crateApiSimpleGreet(name: name);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'entry_names.dart';
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+            
+
+            
+
+            @freezed
+                sealed class SendmeError with _$SendmeError implements FrbException {
+                    const SendmeError._();
+
+                     const factory SendmeError.invalidTicket({   required String reason , }) = SendmeError_InvalidTicket;
+ const factory SendmeError.unknownCode({   required String code , }) = SendmeError_UnknownCode;
+ const factory SendmeError.invalidIdentity({   required String reason , }) = SendmeError_InvalidIdentity;
+ const factory SendmeError.invalidConfig({   required String reason , }) = SendmeError_InvalidConfig;
+ const factory SendmeError.nodeNotStarted() = SendmeError_NodeNotStarted;
+ const factory SendmeError.connectTimeout({   required String nodeId ,  required BigInt timeoutSecs , }) = SendmeError_ConnectTimeout;
+ const factory SendmeError.peerUnreachable({   required String nodeId ,  required String reason , }) = SendmeError_PeerUnreachable;
+ const factory SendmeError.targetExists({   required String path , }) = SendmeError_TargetExists;
+ const factory SendmeError.io({   String? path ,  required String message , }) = SendmeError_Io;
+ const factory SendmeError.storeCorrupt({   required String hash ,  required String message , }) = SendmeError_StoreCorrupt;
+ const factory SendmeError.cancelled({   required BigInt transferId , }) = SendmeError_Cancelled;
+ const factory SendmeError.pathRejected({   required String name ,  required PathRejectReason reason , }) = SendmeError_PathRejected;
+ const factory SendmeError.unknownTransfer({   required String idOrTicket , }) = SendmeError_UnknownTransfer;
+ const factory SendmeError.downloadFailed({   required String nodeId ,  required String reason , }) = SendmeError_DownloadFailed;
+ const factory SendmeError.transferFinished({   required BigInt transferId ,  required TransferState state , }) = SendmeError_TransferFinished;
+ const factory SendmeError.transferRunning({   required BigInt transferId ,  required TransferState state , }) = SendmeError_TransferRunning;
+ const factory SendmeError.noFreeShortCode({   required int attempts , }) = SendmeError_NoFreeShortCode;
+ const factory SendmeError.other({   required String message , }) = SendmeError_Other;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
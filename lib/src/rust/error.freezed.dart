@@ -0,0 +1,4497 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$SendmeError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $SendmeErrorCopyWith<$Res> {
+  factory $SendmeErrorCopyWith(
+          SendmeError value, $Res Function(SendmeError) then) =
+      _$SendmeErrorCopyWithImpl<$Res, SendmeError>;
+}
+
+/// @nodoc
+class _$SendmeErrorCopyWithImpl<$Res, $Val extends SendmeError>
+    implements $SendmeErrorCopyWith<$Res> {
+  _$SendmeErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SendmeError_InvalidTicketImplCopyWith<$Res> {
+  factory _$$SendmeError_InvalidTicketImplCopyWith(
+          _$SendmeError_InvalidTicketImpl value, $Res Function(_$SendmeError_InvalidTicketImpl) then) =
+      __$$SendmeError_InvalidTicketImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String reason});
+}
+
+/// @nodoc
+class __$$SendmeError_InvalidTicketImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_InvalidTicketImpl>
+    implements _$$SendmeError_InvalidTicketImplCopyWith<$Res> {
+  __$$SendmeError_InvalidTicketImplCopyWithImpl(
+      _$SendmeError_InvalidTicketImpl _value, $Res Function(_$SendmeError_InvalidTicketImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? reason = null,
+  }) {
+    return _then(_$SendmeError_InvalidTicketImpl(
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_InvalidTicketImpl extends SendmeError_InvalidTicket {
+  const _$SendmeError_InvalidTicketImpl({required this.reason}) : super._();
+
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'SendmeError.invalidTicket(reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_InvalidTicketImpl &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, reason);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_InvalidTicketImplCopyWith<_$SendmeError_InvalidTicketImpl> get copyWith =>
+      __$$SendmeError_InvalidTicketImplCopyWithImpl<_$SendmeError_InvalidTicketImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return invalidTicket(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return invalidTicket?.call(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidTicket != null) {
+      return invalidTicket(reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return invalidTicket(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return invalidTicket?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidTicket != null) {
+      return invalidTicket(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_InvalidTicket extends SendmeError {
+  const factory SendmeError_InvalidTicket({required final String reason}) =
+      _$SendmeError_InvalidTicketImpl;
+  const SendmeError_InvalidTicket._() : super._();
+
+  String get reason;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_InvalidTicketImplCopyWith<_$SendmeError_InvalidTicketImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_UnknownCodeImplCopyWith<$Res> {
+  factory _$$SendmeError_UnknownCodeImplCopyWith(
+          _$SendmeError_UnknownCodeImpl value, $Res Function(_$SendmeError_UnknownCodeImpl) then) =
+      __$$SendmeError_UnknownCodeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String code});
+}
+
+/// @nodoc
+class __$$SendmeError_UnknownCodeImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_UnknownCodeImpl>
+    implements _$$SendmeError_UnknownCodeImplCopyWith<$Res> {
+  __$$SendmeError_UnknownCodeImplCopyWithImpl(
+      _$SendmeError_UnknownCodeImpl _value, $Res Function(_$SendmeError_UnknownCodeImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? code = null,
+  }) {
+    return _then(_$SendmeError_UnknownCodeImpl(
+      code: null == code
+          ? _value.code
+          : code // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_UnknownCodeImpl extends SendmeError_UnknownCode {
+  const _$SendmeError_UnknownCodeImpl({required this.code}) : super._();
+
+  @override
+  final String code;
+
+  @override
+  String toString() {
+    return 'SendmeError.unknownCode(code: $code)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_UnknownCodeImpl &&
+            (identical(other.code, code) || other.code == code));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, code);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_UnknownCodeImplCopyWith<_$SendmeError_UnknownCodeImpl> get copyWith =>
+      __$$SendmeError_UnknownCodeImplCopyWithImpl<_$SendmeError_UnknownCodeImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return unknownCode(code);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return unknownCode?.call(code);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownCode != null) {
+      return unknownCode(code);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return unknownCode(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return unknownCode?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownCode != null) {
+      return unknownCode(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_UnknownCode extends SendmeError {
+  const factory SendmeError_UnknownCode({required final String code}) =
+      _$SendmeError_UnknownCodeImpl;
+  const SendmeError_UnknownCode._() : super._();
+
+  String get code;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_UnknownCodeImplCopyWith<_$SendmeError_UnknownCodeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_InvalidIdentityImplCopyWith<$Res> {
+  factory _$$SendmeError_InvalidIdentityImplCopyWith(
+          _$SendmeError_InvalidIdentityImpl value, $Res Function(_$SendmeError_InvalidIdentityImpl) then) =
+      __$$SendmeError_InvalidIdentityImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String reason});
+}
+
+/// @nodoc
+class __$$SendmeError_InvalidIdentityImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_InvalidIdentityImpl>
+    implements _$$SendmeError_InvalidIdentityImplCopyWith<$Res> {
+  __$$SendmeError_InvalidIdentityImplCopyWithImpl(
+      _$SendmeError_InvalidIdentityImpl _value, $Res Function(_$SendmeError_InvalidIdentityImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? reason = null,
+  }) {
+    return _then(_$SendmeError_InvalidIdentityImpl(
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_InvalidIdentityImpl extends SendmeError_InvalidIdentity {
+  const _$SendmeError_InvalidIdentityImpl({required this.reason}) : super._();
+
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'SendmeError.invalidIdentity(reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_InvalidIdentityImpl &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, reason);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_InvalidIdentityImplCopyWith<_$SendmeError_InvalidIdentityImpl> get copyWith =>
+      __$$SendmeError_InvalidIdentityImplCopyWithImpl<_$SendmeError_InvalidIdentityImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return invalidIdentity(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return invalidIdentity?.call(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidIdentity != null) {
+      return invalidIdentity(reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return invalidIdentity(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return invalidIdentity?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidIdentity != null) {
+      return invalidIdentity(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_InvalidIdentity extends SendmeError {
+  const factory SendmeError_InvalidIdentity({required final String reason}) =
+      _$SendmeError_InvalidIdentityImpl;
+  const SendmeError_InvalidIdentity._() : super._();
+
+  String get reason;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_InvalidIdentityImplCopyWith<_$SendmeError_InvalidIdentityImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_InvalidConfigImplCopyWith<$Res> {
+  factory _$$SendmeError_InvalidConfigImplCopyWith(
+          _$SendmeError_InvalidConfigImpl value, $Res Function(_$SendmeError_InvalidConfigImpl) then) =
+      __$$SendmeError_InvalidConfigImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String reason});
+}
+
+/// @nodoc
+class __$$SendmeError_InvalidConfigImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_InvalidConfigImpl>
+    implements _$$SendmeError_InvalidConfigImplCopyWith<$Res> {
+  __$$SendmeError_InvalidConfigImplCopyWithImpl(
+      _$SendmeError_InvalidConfigImpl _value, $Res Function(_$SendmeError_InvalidConfigImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? reason = null,
+  }) {
+    return _then(_$SendmeError_InvalidConfigImpl(
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_InvalidConfigImpl extends SendmeError_InvalidConfig {
+  const _$SendmeError_InvalidConfigImpl({required this.reason}) : super._();
+
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'SendmeError.invalidConfig(reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_InvalidConfigImpl &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, reason);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_InvalidConfigImplCopyWith<_$SendmeError_InvalidConfigImpl> get copyWith =>
+      __$$SendmeError_InvalidConfigImplCopyWithImpl<_$SendmeError_InvalidConfigImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return invalidConfig(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return invalidConfig?.call(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidConfig != null) {
+      return invalidConfig(reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return invalidConfig(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return invalidConfig?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (invalidConfig != null) {
+      return invalidConfig(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_InvalidConfig extends SendmeError {
+  const factory SendmeError_InvalidConfig({required final String reason}) =
+      _$SendmeError_InvalidConfigImpl;
+  const SendmeError_InvalidConfig._() : super._();
+
+  String get reason;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_InvalidConfigImplCopyWith<_$SendmeError_InvalidConfigImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_NodeNotStartedImplCopyWith<$Res> {
+  factory _$$SendmeError_NodeNotStartedImplCopyWith(
+          _$SendmeError_NodeNotStartedImpl value, $Res Function(_$SendmeError_NodeNotStartedImpl) then) =
+      __$$SendmeError_NodeNotStartedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$SendmeError_NodeNotStartedImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_NodeNotStartedImpl>
+    implements _$$SendmeError_NodeNotStartedImplCopyWith<$Res> {
+  __$$SendmeError_NodeNotStartedImplCopyWithImpl(
+      _$SendmeError_NodeNotStartedImpl _value, $Res Function(_$SendmeError_NodeNotStartedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$SendmeError_NodeNotStartedImpl extends SendmeError_NodeNotStarted {
+  const _$SendmeError_NodeNotStartedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'SendmeError.nodeNotStarted()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_NodeNotStartedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return nodeNotStarted();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return nodeNotStarted?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (nodeNotStarted != null) {
+      return nodeNotStarted();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return nodeNotStarted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return nodeNotStarted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (nodeNotStarted != null) {
+      return nodeNotStarted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_NodeNotStarted extends SendmeError {
+  const factory SendmeError_NodeNotStarted() =
+      _$SendmeError_NodeNotStartedImpl;
+  const SendmeError_NodeNotStarted._() : super._();
+
+}
+
+/// @nodoc
+abstract class _$$SendmeError_ConnectTimeoutImplCopyWith<$Res> {
+  factory _$$SendmeError_ConnectTimeoutImplCopyWith(
+          _$SendmeError_ConnectTimeoutImpl value, $Res Function(_$SendmeError_ConnectTimeoutImpl) then) =
+      __$$SendmeError_ConnectTimeoutImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String nodeId, BigInt timeoutSecs});
+}
+
+/// @nodoc
+class __$$SendmeError_ConnectTimeoutImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_ConnectTimeoutImpl>
+    implements _$$SendmeError_ConnectTimeoutImplCopyWith<$Res> {
+  __$$SendmeError_ConnectTimeoutImplCopyWithImpl(
+      _$SendmeError_ConnectTimeoutImpl _value, $Res Function(_$SendmeError_ConnectTimeoutImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? nodeId = null,
+    Object? timeoutSecs = null,
+  }) {
+    return _then(_$SendmeError_ConnectTimeoutImpl(
+      nodeId: null == nodeId
+          ? _value.nodeId
+          : nodeId // ignore: cast_nullable_to_non_nullable
+              as String,
+      timeoutSecs: null == timeoutSecs
+          ? _value.timeoutSecs
+          : timeoutSecs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_ConnectTimeoutImpl extends SendmeError_ConnectTimeout {
+  const _$SendmeError_ConnectTimeoutImpl({required this.nodeId, required this.timeoutSecs}) : super._();
+
+  @override
+  final String nodeId;
+  @override
+  final BigInt timeoutSecs;
+
+  @override
+  String toString() {
+    return 'SendmeError.connectTimeout(nodeId: $nodeId, timeoutSecs: $timeoutSecs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_ConnectTimeoutImpl &&
+            (identical(other.nodeId, nodeId) || other.nodeId == nodeId) &&
+            (identical(other.timeoutSecs, timeoutSecs) || other.timeoutSecs == timeoutSecs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, nodeId, timeoutSecs);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_ConnectTimeoutImplCopyWith<_$SendmeError_ConnectTimeoutImpl> get copyWith =>
+      __$$SendmeError_ConnectTimeoutImplCopyWithImpl<_$SendmeError_ConnectTimeoutImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return connectTimeout(nodeId, timeoutSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return connectTimeout?.call(nodeId, timeoutSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (connectTimeout != null) {
+      return connectTimeout(nodeId, timeoutSecs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return connectTimeout(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return connectTimeout?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (connectTimeout != null) {
+      return connectTimeout(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_ConnectTimeout extends SendmeError {
+  const factory SendmeError_ConnectTimeout({required final String nodeId, required final BigInt timeoutSecs}) =
+      _$SendmeError_ConnectTimeoutImpl;
+  const SendmeError_ConnectTimeout._() : super._();
+
+  String get nodeId;
+  BigInt get timeoutSecs;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_ConnectTimeoutImplCopyWith<_$SendmeError_ConnectTimeoutImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_PeerUnreachableImplCopyWith<$Res> {
+  factory _$$SendmeError_PeerUnreachableImplCopyWith(
+          _$SendmeError_PeerUnreachableImpl value, $Res Function(_$SendmeError_PeerUnreachableImpl) then) =
+      __$$SendmeError_PeerUnreachableImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String nodeId, String reason});
+}
+
+/// @nodoc
+class __$$SendmeError_PeerUnreachableImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_PeerUnreachableImpl>
+    implements _$$SendmeError_PeerUnreachableImplCopyWith<$Res> {
+  __$$SendmeError_PeerUnreachableImplCopyWithImpl(
+      _$SendmeError_PeerUnreachableImpl _value, $Res Function(_$SendmeError_PeerUnreachableImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? nodeId = null,
+    Object? reason = null,
+  }) {
+    return _then(_$SendmeError_PeerUnreachableImpl(
+      nodeId: null == nodeId
+          ? _value.nodeId
+          : nodeId // ignore: cast_nullable_to_non_nullable
+              as String,
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_PeerUnreachableImpl extends SendmeError_PeerUnreachable {
+  const _$SendmeError_PeerUnreachableImpl({required this.nodeId, required this.reason}) : super._();
+
+  @override
+  final String nodeId;
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'SendmeError.peerUnreachable(nodeId: $nodeId, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_PeerUnreachableImpl &&
+            (identical(other.nodeId, nodeId) || other.nodeId == nodeId) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, nodeId, reason);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_PeerUnreachableImplCopyWith<_$SendmeError_PeerUnreachableImpl> get copyWith =>
+      __$$SendmeError_PeerUnreachableImplCopyWithImpl<_$SendmeError_PeerUnreachableImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return peerUnreachable(nodeId, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return peerUnreachable?.call(nodeId, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (peerUnreachable != null) {
+      return peerUnreachable(nodeId, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return peerUnreachable(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return peerUnreachable?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (peerUnreachable != null) {
+      return peerUnreachable(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_PeerUnreachable extends SendmeError {
+  const factory SendmeError_PeerUnreachable({required final String nodeId, required final String reason}) =
+      _$SendmeError_PeerUnreachableImpl;
+  const SendmeError_PeerUnreachable._() : super._();
+
+  String get nodeId;
+  String get reason;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_PeerUnreachableImplCopyWith<_$SendmeError_PeerUnreachableImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_TargetExistsImplCopyWith<$Res> {
+  factory _$$SendmeError_TargetExistsImplCopyWith(
+          _$SendmeError_TargetExistsImpl value, $Res Function(_$SendmeError_TargetExistsImpl) then) =
+      __$$SendmeError_TargetExistsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path});
+}
+
+/// @nodoc
+class __$$SendmeError_TargetExistsImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_TargetExistsImpl>
+    implements _$$SendmeError_TargetExistsImplCopyWith<$Res> {
+  __$$SendmeError_TargetExistsImplCopyWithImpl(
+      _$SendmeError_TargetExistsImpl _value, $Res Function(_$SendmeError_TargetExistsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+  }) {
+    return _then(_$SendmeError_TargetExistsImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_TargetExistsImpl extends SendmeError_TargetExists {
+  const _$SendmeError_TargetExistsImpl({required this.path}) : super._();
+
+  @override
+  final String path;
+
+  @override
+  String toString() {
+    return 'SendmeError.targetExists(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_TargetExistsImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_TargetExistsImplCopyWith<_$SendmeError_TargetExistsImpl> get copyWith =>
+      __$$SendmeError_TargetExistsImplCopyWithImpl<_$SendmeError_TargetExistsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return targetExists(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return targetExists?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (targetExists != null) {
+      return targetExists(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return targetExists(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return targetExists?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (targetExists != null) {
+      return targetExists(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_TargetExists extends SendmeError {
+  const factory SendmeError_TargetExists({required final String path}) =
+      _$SendmeError_TargetExistsImpl;
+  const SendmeError_TargetExists._() : super._();
+
+  String get path;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_TargetExistsImplCopyWith<_$SendmeError_TargetExistsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_IoImplCopyWith<$Res> {
+  factory _$$SendmeError_IoImplCopyWith(
+          _$SendmeError_IoImpl value, $Res Function(_$SendmeError_IoImpl) then) =
+      __$$SendmeError_IoImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? path, String message});
+}
+
+/// @nodoc
+class __$$SendmeError_IoImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_IoImpl>
+    implements _$$SendmeError_IoImplCopyWith<$Res> {
+  __$$SendmeError_IoImplCopyWithImpl(
+      _$SendmeError_IoImpl _value, $Res Function(_$SendmeError_IoImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = freezed,
+    Object? message = null,
+  }) {
+    return _then(_$SendmeError_IoImpl(
+      path: freezed == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String?,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_IoImpl extends SendmeError_Io {
+  const _$SendmeError_IoImpl({this.path, required this.message}) : super._();
+
+  @override
+  final String? path;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'SendmeError.io(path: $path, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_IoImpl &&
+            (identical(other.path, path) || other.path == path) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path, message);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_IoImplCopyWith<_$SendmeError_IoImpl> get copyWith =>
+      __$$SendmeError_IoImplCopyWithImpl<_$SendmeError_IoImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return io(path, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return io?.call(path, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(path, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return io(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return io?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_Io extends SendmeError {
+  const factory SendmeError_Io({final String? path, required final String message}) =
+      _$SendmeError_IoImpl;
+  const SendmeError_Io._() : super._();
+
+  String? get path;
+  String get message;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_IoImplCopyWith<_$SendmeError_IoImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_StoreCorruptImplCopyWith<$Res> {
+  factory _$$SendmeError_StoreCorruptImplCopyWith(
+          _$SendmeError_StoreCorruptImpl value, $Res Function(_$SendmeError_StoreCorruptImpl) then) =
+      __$$SendmeError_StoreCorruptImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String hash, String message});
+}
+
+/// @nodoc
+class __$$SendmeError_StoreCorruptImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_StoreCorruptImpl>
+    implements _$$SendmeError_StoreCorruptImplCopyWith<$Res> {
+  __$$SendmeError_StoreCorruptImplCopyWithImpl(
+      _$SendmeError_StoreCorruptImpl _value, $Res Function(_$SendmeError_StoreCorruptImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? hash = null,
+    Object? message = null,
+  }) {
+    return _then(_$SendmeError_StoreCorruptImpl(
+      hash: null == hash
+          ? _value.hash
+          : hash // ignore: cast_nullable_to_non_nullable
+              as String,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_StoreCorruptImpl extends SendmeError_StoreCorrupt {
+  const _$SendmeError_StoreCorruptImpl({required this.hash, required this.message}) : super._();
+
+  @override
+  final String hash;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'SendmeError.storeCorrupt(hash: $hash, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_StoreCorruptImpl &&
+            (identical(other.hash, hash) || other.hash == hash) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, hash, message);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_StoreCorruptImplCopyWith<_$SendmeError_StoreCorruptImpl> get copyWith =>
+      __$$SendmeError_StoreCorruptImplCopyWithImpl<_$SendmeError_StoreCorruptImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return storeCorrupt(hash, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return storeCorrupt?.call(hash, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (storeCorrupt != null) {
+      return storeCorrupt(hash, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return storeCorrupt(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return storeCorrupt?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (storeCorrupt != null) {
+      return storeCorrupt(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_StoreCorrupt extends SendmeError {
+  const factory SendmeError_StoreCorrupt({required final String hash, required final String message}) =
+      _$SendmeError_StoreCorruptImpl;
+  const SendmeError_StoreCorrupt._() : super._();
+
+  String get hash;
+  String get message;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_StoreCorruptImplCopyWith<_$SendmeError_StoreCorruptImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_CancelledImplCopyWith<$Res> {
+  factory _$$SendmeError_CancelledImplCopyWith(
+          _$SendmeError_CancelledImpl value, $Res Function(_$SendmeError_CancelledImpl) then) =
+      __$$SendmeError_CancelledImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt transferId});
+}
+
+/// @nodoc
+class __$$SendmeError_CancelledImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_CancelledImpl>
+    implements _$$SendmeError_CancelledImplCopyWith<$Res> {
+  __$$SendmeError_CancelledImplCopyWithImpl(
+      _$SendmeError_CancelledImpl _value, $Res Function(_$SendmeError_CancelledImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+  }) {
+    return _then(_$SendmeError_CancelledImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_CancelledImpl extends SendmeError_Cancelled {
+  const _$SendmeError_CancelledImpl({required this.transferId}) : super._();
+
+  @override
+  final BigInt transferId;
+
+  @override
+  String toString() {
+    return 'SendmeError.cancelled(transferId: $transferId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_CancelledImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_CancelledImplCopyWith<_$SendmeError_CancelledImpl> get copyWith =>
+      __$$SendmeError_CancelledImplCopyWithImpl<_$SendmeError_CancelledImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return cancelled(transferId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return cancelled?.call(transferId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(transferId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return cancelled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return cancelled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_Cancelled extends SendmeError {
+  const factory SendmeError_Cancelled({required final BigInt transferId}) =
+      _$SendmeError_CancelledImpl;
+  const SendmeError_Cancelled._() : super._();
+
+  BigInt get transferId;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_CancelledImplCopyWith<_$SendmeError_CancelledImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_PathRejectedImplCopyWith<$Res> {
+  factory _$$SendmeError_PathRejectedImplCopyWith(
+          _$SendmeError_PathRejectedImpl value, $Res Function(_$SendmeError_PathRejectedImpl) then) =
+      __$$SendmeError_PathRejectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String name, PathRejectReason reason});
+}
+
+/// @nodoc
+class __$$SendmeError_PathRejectedImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_PathRejectedImpl>
+    implements _$$SendmeError_PathRejectedImplCopyWith<$Res> {
+  __$$SendmeError_PathRejectedImplCopyWithImpl(
+      _$SendmeError_PathRejectedImpl _value, $Res Function(_$SendmeError_PathRejectedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? reason = null,
+  }) {
+    return _then(_$SendmeError_PathRejectedImpl(
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as PathRejectReason,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_PathRejectedImpl extends SendmeError_PathRejected {
+  const _$SendmeError_PathRejectedImpl({required this.name, required this.reason}) : super._();
+
+  @override
+  final String name;
+  @override
+  final PathRejectReason reason;
+
+  @override
+  String toString() {
+    return 'SendmeError.pathRejected(name: $name, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_PathRejectedImpl &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, reason);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_PathRejectedImplCopyWith<_$SendmeError_PathRejectedImpl> get copyWith =>
+      __$$SendmeError_PathRejectedImplCopyWithImpl<_$SendmeError_PathRejectedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return pathRejected(name, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return pathRejected?.call(name, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (pathRejected != null) {
+      return pathRejected(name, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return pathRejected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return pathRejected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (pathRejected != null) {
+      return pathRejected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_PathRejected extends SendmeError {
+  const factory SendmeError_PathRejected({required final String name, required final PathRejectReason reason}) =
+      _$SendmeError_PathRejectedImpl;
+  const SendmeError_PathRejected._() : super._();
+
+  String get name;
+  PathRejectReason get reason;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_PathRejectedImplCopyWith<_$SendmeError_PathRejectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_UnknownTransferImplCopyWith<$Res> {
+  factory _$$SendmeError_UnknownTransferImplCopyWith(
+          _$SendmeError_UnknownTransferImpl value, $Res Function(_$SendmeError_UnknownTransferImpl) then) =
+      __$$SendmeError_UnknownTransferImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String idOrTicket});
+}
+
+/// @nodoc
+class __$$SendmeError_UnknownTransferImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_UnknownTransferImpl>
+    implements _$$SendmeError_UnknownTransferImplCopyWith<$Res> {
+  __$$SendmeError_UnknownTransferImplCopyWithImpl(
+      _$SendmeError_UnknownTransferImpl _value, $Res Function(_$SendmeError_UnknownTransferImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? idOrTicket = null,
+  }) {
+    return _then(_$SendmeError_UnknownTransferImpl(
+      idOrTicket: null == idOrTicket
+          ? _value.idOrTicket
+          : idOrTicket // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_UnknownTransferImpl extends SendmeError_UnknownTransfer {
+  const _$SendmeError_UnknownTransferImpl({required this.idOrTicket}) : super._();
+
+  @override
+  final String idOrTicket;
+
+  @override
+  String toString() {
+    return 'SendmeError.unknownTransfer(idOrTicket: $idOrTicket)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_UnknownTransferImpl &&
+            (identical(other.idOrTicket, idOrTicket) || other.idOrTicket == idOrTicket));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, idOrTicket);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_UnknownTransferImplCopyWith<_$SendmeError_UnknownTransferImpl> get copyWith =>
+      __$$SendmeError_UnknownTransferImplCopyWithImpl<_$SendmeError_UnknownTransferImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return unknownTransfer(idOrTicket);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return unknownTransfer?.call(idOrTicket);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownTransfer != null) {
+      return unknownTransfer(idOrTicket);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return unknownTransfer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return unknownTransfer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unknownTransfer != null) {
+      return unknownTransfer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_UnknownTransfer extends SendmeError {
+  const factory SendmeError_UnknownTransfer({required final String idOrTicket}) =
+      _$SendmeError_UnknownTransferImpl;
+  const SendmeError_UnknownTransfer._() : super._();
+
+  String get idOrTicket;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_UnknownTransferImplCopyWith<_$SendmeError_UnknownTransferImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_DownloadFailedImplCopyWith<$Res> {
+  factory _$$SendmeError_DownloadFailedImplCopyWith(
+          _$SendmeError_DownloadFailedImpl value, $Res Function(_$SendmeError_DownloadFailedImpl) then) =
+      __$$SendmeError_DownloadFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String nodeId, String reason});
+}
+
+/// @nodoc
+class __$$SendmeError_DownloadFailedImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_DownloadFailedImpl>
+    implements _$$SendmeError_DownloadFailedImplCopyWith<$Res> {
+  __$$SendmeError_DownloadFailedImplCopyWithImpl(
+      _$SendmeError_DownloadFailedImpl _value, $Res Function(_$SendmeError_DownloadFailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? nodeId = null,
+    Object? reason = null,
+  }) {
+    return _then(_$SendmeError_DownloadFailedImpl(
+      nodeId: null == nodeId
+          ? _value.nodeId
+          : nodeId // ignore: cast_nullable_to_non_nullable
+              as String,
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_DownloadFailedImpl extends SendmeError_DownloadFailed {
+  const _$SendmeError_DownloadFailedImpl({required this.nodeId, required this.reason}) : super._();
+
+  @override
+  final String nodeId;
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'SendmeError.downloadFailed(nodeId: $nodeId, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_DownloadFailedImpl &&
+            (identical(other.nodeId, nodeId) || other.nodeId == nodeId) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, nodeId, reason);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_DownloadFailedImplCopyWith<_$SendmeError_DownloadFailedImpl> get copyWith =>
+      __$$SendmeError_DownloadFailedImplCopyWithImpl<_$SendmeError_DownloadFailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return downloadFailed(nodeId, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return downloadFailed?.call(nodeId, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (downloadFailed != null) {
+      return downloadFailed(nodeId, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return downloadFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return downloadFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (downloadFailed != null) {
+      return downloadFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_DownloadFailed extends SendmeError {
+  const factory SendmeError_DownloadFailed({required final String nodeId, required final String reason}) =
+      _$SendmeError_DownloadFailedImpl;
+  const SendmeError_DownloadFailed._() : super._();
+
+  String get nodeId;
+  String get reason;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_DownloadFailedImplCopyWith<_$SendmeError_DownloadFailedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_TransferFinishedImplCopyWith<$Res> {
+  factory _$$SendmeError_TransferFinishedImplCopyWith(
+          _$SendmeError_TransferFinishedImpl value, $Res Function(_$SendmeError_TransferFinishedImpl) then) =
+      __$$SendmeError_TransferFinishedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt transferId, TransferState state});
+}
+
+/// @nodoc
+class __$$SendmeError_TransferFinishedImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_TransferFinishedImpl>
+    implements _$$SendmeError_TransferFinishedImplCopyWith<$Res> {
+  __$$SendmeError_TransferFinishedImplCopyWithImpl(
+      _$SendmeError_TransferFinishedImpl _value, $Res Function(_$SendmeError_TransferFinishedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? state = null,
+  }) {
+    return _then(_$SendmeError_TransferFinishedImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      state: null == state
+          ? _value.state
+          : state // ignore: cast_nullable_to_non_nullable
+              as TransferState,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_TransferFinishedImpl extends SendmeError_TransferFinished {
+  const _$SendmeError_TransferFinishedImpl({required this.transferId, required this.state}) : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final TransferState state;
+
+  @override
+  String toString() {
+    return 'SendmeError.transferFinished(transferId: $transferId, state: $state)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_TransferFinishedImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.state, state) || other.state == state));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, state);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_TransferFinishedImplCopyWith<_$SendmeError_TransferFinishedImpl> get copyWith =>
+      __$$SendmeError_TransferFinishedImplCopyWithImpl<_$SendmeError_TransferFinishedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return transferFinished(transferId, state);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return transferFinished?.call(transferId, state);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (transferFinished != null) {
+      return transferFinished(transferId, state);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return transferFinished(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return transferFinished?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (transferFinished != null) {
+      return transferFinished(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_TransferFinished extends SendmeError {
+  const factory SendmeError_TransferFinished({required final BigInt transferId, required final TransferState state}) =
+      _$SendmeError_TransferFinishedImpl;
+  const SendmeError_TransferFinished._() : super._();
+
+  BigInt get transferId;
+  TransferState get state;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_TransferFinishedImplCopyWith<_$SendmeError_TransferFinishedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_TransferRunningImplCopyWith<$Res> {
+  factory _$$SendmeError_TransferRunningImplCopyWith(
+          _$SendmeError_TransferRunningImpl value, $Res Function(_$SendmeError_TransferRunningImpl) then) =
+      __$$SendmeError_TransferRunningImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt transferId, TransferState state});
+}
+
+/// @nodoc
+class __$$SendmeError_TransferRunningImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_TransferRunningImpl>
+    implements _$$SendmeError_TransferRunningImplCopyWith<$Res> {
+  __$$SendmeError_TransferRunningImplCopyWithImpl(
+      _$SendmeError_TransferRunningImpl _value, $Res Function(_$SendmeError_TransferRunningImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? state = null,
+  }) {
+    return _then(_$SendmeError_TransferRunningImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      state: null == state
+          ? _value.state
+          : state // ignore: cast_nullable_to_non_nullable
+              as TransferState,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_TransferRunningImpl extends SendmeError_TransferRunning {
+  const _$SendmeError_TransferRunningImpl({required this.transferId, required this.state}) : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final TransferState state;
+
+  @override
+  String toString() {
+    return 'SendmeError.transferRunning(transferId: $transferId, state: $state)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_TransferRunningImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.state, state) || other.state == state));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, state);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_TransferRunningImplCopyWith<_$SendmeError_TransferRunningImpl> get copyWith =>
+      __$$SendmeError_TransferRunningImplCopyWithImpl<_$SendmeError_TransferRunningImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return transferRunning(transferId, state);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return transferRunning?.call(transferId, state);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (transferRunning != null) {
+      return transferRunning(transferId, state);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return transferRunning(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return transferRunning?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (transferRunning != null) {
+      return transferRunning(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_TransferRunning extends SendmeError {
+  const factory SendmeError_TransferRunning({required final BigInt transferId, required final TransferState state}) =
+      _$SendmeError_TransferRunningImpl;
+  const SendmeError_TransferRunning._() : super._();
+
+  BigInt get transferId;
+  TransferState get state;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_TransferRunningImplCopyWith<_$SendmeError_TransferRunningImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_NoFreeShortCodeImplCopyWith<$Res> {
+  factory _$$SendmeError_NoFreeShortCodeImplCopyWith(
+          _$SendmeError_NoFreeShortCodeImpl value, $Res Function(_$SendmeError_NoFreeShortCodeImpl) then) =
+      __$$SendmeError_NoFreeShortCodeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int attempts});
+}
+
+/// @nodoc
+class __$$SendmeError_NoFreeShortCodeImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_NoFreeShortCodeImpl>
+    implements _$$SendmeError_NoFreeShortCodeImplCopyWith<$Res> {
+  __$$SendmeError_NoFreeShortCodeImplCopyWithImpl(
+      _$SendmeError_NoFreeShortCodeImpl _value, $Res Function(_$SendmeError_NoFreeShortCodeImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? attempts = null,
+  }) {
+    return _then(_$SendmeError_NoFreeShortCodeImpl(
+      attempts: null == attempts
+          ? _value.attempts
+          : attempts // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_NoFreeShortCodeImpl extends SendmeError_NoFreeShortCode {
+  const _$SendmeError_NoFreeShortCodeImpl({required this.attempts}) : super._();
+
+  @override
+  final int attempts;
+
+  @override
+  String toString() {
+    return 'SendmeError.noFreeShortCode(attempts: $attempts)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_NoFreeShortCodeImpl &&
+            (identical(other.attempts, attempts) || other.attempts == attempts));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, attempts);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_NoFreeShortCodeImplCopyWith<_$SendmeError_NoFreeShortCodeImpl> get copyWith =>
+      __$$SendmeError_NoFreeShortCodeImplCopyWithImpl<_$SendmeError_NoFreeShortCodeImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return noFreeShortCode(attempts);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return noFreeShortCode?.call(attempts);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (noFreeShortCode != null) {
+      return noFreeShortCode(attempts);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return noFreeShortCode(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return noFreeShortCode?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (noFreeShortCode != null) {
+      return noFreeShortCode(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_NoFreeShortCode extends SendmeError {
+  const factory SendmeError_NoFreeShortCode({required final int attempts}) =
+      _$SendmeError_NoFreeShortCodeImpl;
+  const SendmeError_NoFreeShortCode._() : super._();
+
+  int get attempts;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_NoFreeShortCodeImplCopyWith<_$SendmeError_NoFreeShortCodeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SendmeError_OtherImplCopyWith<$Res> {
+  factory _$$SendmeError_OtherImplCopyWith(
+          _$SendmeError_OtherImpl value, $Res Function(_$SendmeError_OtherImpl) then) =
+      __$$SendmeError_OtherImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$SendmeError_OtherImplCopyWithImpl<$Res>
+    extends _$SendmeErrorCopyWithImpl<$Res, _$SendmeError_OtherImpl>
+    implements _$$SendmeError_OtherImplCopyWith<$Res> {
+  __$$SendmeError_OtherImplCopyWithImpl(
+      _$SendmeError_OtherImpl _value, $Res Function(_$SendmeError_OtherImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$SendmeError_OtherImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SendmeError_OtherImpl extends SendmeError_Other {
+  const _$SendmeError_OtherImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'SendmeError.other(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SendmeError_OtherImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SendmeError_OtherImplCopyWith<_$SendmeError_OtherImpl> get copyWith =>
+      __$$SendmeError_OtherImplCopyWithImpl<_$SendmeError_OtherImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String reason) invalidTicket,
+    required TResult Function(String code) unknownCode,
+    required TResult Function(String reason) invalidIdentity,
+    required TResult Function(String reason) invalidConfig,
+    required TResult Function() nodeNotStarted,
+    required TResult Function(String nodeId, BigInt timeoutSecs) connectTimeout,
+    required TResult Function(String nodeId, String reason) peerUnreachable,
+    required TResult Function(String path) targetExists,
+    required TResult Function(String? path, String message) io,
+    required TResult Function(String hash, String message) storeCorrupt,
+    required TResult Function(BigInt transferId) cancelled,
+    required TResult Function(String name, PathRejectReason reason) pathRejected,
+    required TResult Function(String idOrTicket) unknownTransfer,
+    required TResult Function(String nodeId, String reason) downloadFailed,
+    required TResult Function(BigInt transferId, TransferState state) transferFinished,
+    required TResult Function(BigInt transferId, TransferState state) transferRunning,
+    required TResult Function(int attempts) noFreeShortCode,
+    required TResult Function(String message) other,
+  }) {
+    return other(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String reason)? invalidTicket,
+    TResult? Function(String code)? unknownCode,
+    TResult? Function(String reason)? invalidIdentity,
+    TResult? Function(String reason)? invalidConfig,
+    TResult? Function()? nodeNotStarted,
+    TResult? Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult? Function(String nodeId, String reason)? peerUnreachable,
+    TResult? Function(String path)? targetExists,
+    TResult? Function(String? path, String message)? io,
+    TResult? Function(String hash, String message)? storeCorrupt,
+    TResult? Function(BigInt transferId)? cancelled,
+    TResult? Function(String name, PathRejectReason reason)? pathRejected,
+    TResult? Function(String idOrTicket)? unknownTransfer,
+    TResult? Function(String nodeId, String reason)? downloadFailed,
+    TResult? Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult? Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult? Function(int attempts)? noFreeShortCode,
+    TResult? Function(String message)? other,
+  }) {
+    return other?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String reason)? invalidTicket,
+    TResult Function(String code)? unknownCode,
+    TResult Function(String reason)? invalidIdentity,
+    TResult Function(String reason)? invalidConfig,
+    TResult Function()? nodeNotStarted,
+    TResult Function(String nodeId, BigInt timeoutSecs)? connectTimeout,
+    TResult Function(String nodeId, String reason)? peerUnreachable,
+    TResult Function(String path)? targetExists,
+    TResult Function(String? path, String message)? io,
+    TResult Function(String hash, String message)? storeCorrupt,
+    TResult Function(BigInt transferId)? cancelled,
+    TResult Function(String name, PathRejectReason reason)? pathRejected,
+    TResult Function(String idOrTicket)? unknownTransfer,
+    TResult Function(String nodeId, String reason)? downloadFailed,
+    TResult Function(BigInt transferId, TransferState state)? transferFinished,
+    TResult Function(BigInt transferId, TransferState state)? transferRunning,
+    TResult Function(int attempts)? noFreeShortCode,
+    TResult Function(String message)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SendmeError_InvalidTicket value) invalidTicket,
+    required TResult Function(SendmeError_UnknownCode value) unknownCode,
+    required TResult Function(SendmeError_InvalidIdentity value) invalidIdentity,
+    required TResult Function(SendmeError_InvalidConfig value) invalidConfig,
+    required TResult Function(SendmeError_NodeNotStarted value) nodeNotStarted,
+    required TResult Function(SendmeError_ConnectTimeout value) connectTimeout,
+    required TResult Function(SendmeError_PeerUnreachable value) peerUnreachable,
+    required TResult Function(SendmeError_TargetExists value) targetExists,
+    required TResult Function(SendmeError_Io value) io,
+    required TResult Function(SendmeError_StoreCorrupt value) storeCorrupt,
+    required TResult Function(SendmeError_Cancelled value) cancelled,
+    required TResult Function(SendmeError_PathRejected value) pathRejected,
+    required TResult Function(SendmeError_UnknownTransfer value) unknownTransfer,
+    required TResult Function(SendmeError_DownloadFailed value) downloadFailed,
+    required TResult Function(SendmeError_TransferFinished value) transferFinished,
+    required TResult Function(SendmeError_TransferRunning value) transferRunning,
+    required TResult Function(SendmeError_NoFreeShortCode value) noFreeShortCode,
+    required TResult Function(SendmeError_Other value) other,
+  }) {
+    return other(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult? Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult? Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult? Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult? Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult? Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult? Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult? Function(SendmeError_TargetExists value)? targetExists,
+    TResult? Function(SendmeError_Io value)? io,
+    TResult? Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult? Function(SendmeError_Cancelled value)? cancelled,
+    TResult? Function(SendmeError_PathRejected value)? pathRejected,
+    TResult? Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult? Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult? Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult? Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult? Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult? Function(SendmeError_Other value)? other,
+  }) {
+    return other?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SendmeError_InvalidTicket value)? invalidTicket,
+    TResult Function(SendmeError_UnknownCode value)? unknownCode,
+    TResult Function(SendmeError_InvalidIdentity value)? invalidIdentity,
+    TResult Function(SendmeError_InvalidConfig value)? invalidConfig,
+    TResult Function(SendmeError_NodeNotStarted value)? nodeNotStarted,
+    TResult Function(SendmeError_ConnectTimeout value)? connectTimeout,
+    TResult Function(SendmeError_PeerUnreachable value)? peerUnreachable,
+    TResult Function(SendmeError_TargetExists value)? targetExists,
+    TResult Function(SendmeError_Io value)? io,
+    TResult Function(SendmeError_StoreCorrupt value)? storeCorrupt,
+    TResult Function(SendmeError_Cancelled value)? cancelled,
+    TResult Function(SendmeError_PathRejected value)? pathRejected,
+    TResult Function(SendmeError_UnknownTransfer value)? unknownTransfer,
+    TResult Function(SendmeError_DownloadFailed value)? downloadFailed,
+    TResult Function(SendmeError_TransferFinished value)? transferFinished,
+    TResult Function(SendmeError_TransferRunning value)? transferRunning,
+    TResult Function(SendmeError_NoFreeShortCode value)? noFreeShortCode,
+    TResult Function(SendmeError_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SendmeError_Other extends SendmeError {
+  const factory SendmeError_Other({required final String message}) =
+      _$SendmeError_OtherImpl;
+  const SendmeError_Other._() : super._();
+
+  String get message;
+
+  /// Create a copy of SendmeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SendmeError_OtherImplCopyWith<_$SendmeError_OtherImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
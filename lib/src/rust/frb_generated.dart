@@ -3,463 +3,2112 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/identity.dart';
+import 'api/node.dart';
 import 'api/sendme.dart';
+import 'api/short_code.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'entry_names.dart';
+import 'error.dart';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -2052996009;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_sendme',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  String crateApiSendmeFormatBytes({required BigInt size});
-
-  String crateApiSimpleGreet({required String name});
-
-  Future<void> crateApiSimpleInitApp();
-
-  void crateApiSendmeInitLogging();
-
-  Future<ReceiveResult> crateApiSendmeReceiveFile({required String ticket});
-
-  Future<SendResult> crateApiSendmeSendFile({required String path});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  String crateApiSendmeFormatBytes({required BigInt size}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(size, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -1579572992;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_sendme',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiSendmeCancelTransfer({required BigInt id , required bool keepPartial });
+
+BigInt crateApiSendmeCreateReceive();
+
+EndpointConfig crateApiSendmeDefaultEndpointConfig();
+
+QrOptions crateApiSendmeDefaultQrOptions();
+
+Future<void> crateApiSendmeDeletePartialDownload({required String hash , String? tempDir });
+
+String crateApiIdentityExportIdentity();
+
+void crateApiSendmeForgetTransfer({required BigInt id });
+
+String crateApiSendmeFormatBytes({required BigInt size });
+
+TransferInfo? crateApiSendmeGetTransfer({required BigInt id });
+
+String crateApiSimpleGreet({required String name });
+
+Future<String> crateApiIdentityImportIdentity({required String secret });
+
+Future<void> crateApiSimpleInitApp();
+
+Future<String> crateApiIdentityInitIdentity({required String configDir });
+
+void crateApiSendmeInitLogging();
+
+Future<String> crateApiNodeInitNode({required NodeOptions options });
+
+EndpointConfig crateApiSendmeLanOnlyEndpointConfig();
+
+Future<List<PartialDownload>> crateApiSendmeListPartialDownloads({String? tempDir });
+
+List<TransferInfo> crateApiSendmeListTransfers();
+
+String crateApiIdentityNodeId();
+
+TicketInfo crateApiSendmeParseTicket({required String ticket });
+
+Stream<ProgressInfo> crateApiSendmeProgressStream();
+
+Future<String> crateApiShortCodePublishShortCode({required BigInt transferId });
+
+QrImage crateApiSendmeQrCode({required String data , required QrOptions options });
+
+Future<ReceiveResult> crateApiSendmeReceiveFile({required BigInt transferId , required String ticket , required ReceiveOptions options });
+
+Future<String> crateApiShortCodeResolveShortCode({required String code });
+
+Future<String> crateApiIdentityRotateIdentity();
+
+Stream<SendEvent> crateApiSendmeSendEventStream();
+
+Future<SendResult> crateApiSendmeSendFile({required String path , required SendOptions options });
+
+Future<SendResult> crateApiSendmeSendFiles({required List<String> paths , required SendOptions options });
+
+void crateApiShortCodeSetCodeResolver({required CodeResolverConfig config });
+
+void crateApiSendmeSetLocale({required MessageLocale locale });
+
+Future<void> crateApiNodeShutdownNode();
+
+Future<StopSharingResult> crateApiSendmeStopSharing({required String ticketOrId });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiSendmeCancelTransfer({required BigInt id , required bool keepPartial })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(id, serializer);
+sse_encode_bool(keepPartial, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeCancelTransferConstMeta,
+            argValues: [id, keepPartial],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeCancelTransferConstMeta => const TaskConstMeta(
+            debugName: "cancel_transfer",
+            argNames: ["id", "keepPartial"],
+        );
+        
+
+@override BigInt crateApiSendmeCreateReceive()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeCreateReceiveConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeCreateReceiveConstMeta => const TaskConstMeta(
+            debugName: "create_receive",
+            argNames: [],
+        );
+        
+
+@override EndpointConfig crateApiSendmeDefaultEndpointConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_endpoint_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeDefaultEndpointConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeDefaultEndpointConfigConstMeta => const TaskConstMeta(
+            debugName: "default_endpoint_config",
+            argNames: [],
+        );
+        
+
+@override QrOptions crateApiSendmeDefaultQrOptions()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_qr_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeDefaultQrOptionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeDefaultQrOptionsConstMeta => const TaskConstMeta(
+            debugName: "default_qr_options",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSendmeDeletePartialDownload({required String hash , String? tempDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(hash, serializer);
+sse_encode_opt_String(tempDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeDeletePartialDownloadConstMeta,
+            argValues: [hash, tempDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeDeletePartialDownloadConstMeta => const TaskConstMeta(
+            debugName: "delete_partial_download",
+            argNames: ["hash", "tempDir"],
+        );
+        
+
+@override String crateApiIdentityExportIdentity()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIdentityExportIdentityConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIdentityExportIdentityConstMeta => const TaskConstMeta(
+            debugName: "export_identity",
+            argNames: [],
+        );
+        
+
+@override void crateApiSendmeForgetTransfer({required BigInt id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeForgetTransferConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeForgetTransferConstMeta => const TaskConstMeta(
+            debugName: "forget_transfer",
+            argNames: ["id"],
+        );
+        
+
+@override String crateApiSendmeFormatBytes({required BigInt size })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(size, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSendmeFormatBytesConstMeta,
-        argValues: [size],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSendmeFormatBytesConstMeta =>
-      const TaskConstMeta(debugName: "format_bytes", argNames: ["size"]);
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSendmeFormatBytesConstMeta,
+            argValues: [size],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeFormatBytesConstMeta => const TaskConstMeta(
+            debugName: "format_bytes",
+            argNames: ["size"],
+        );
+        
+
+@override TransferInfo? crateApiSendmeGetTransfer({required BigInt id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_transfer_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeGetTransferConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeGetTransferConstMeta => const TaskConstMeta(
+            debugName: "get_transfer",
+            argNames: ["id"],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<String> crateApiIdentityImportIdentity({required String secret })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(secret, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiIdentityImportIdentityConstMeta,
+            argValues: [secret],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIdentityImportIdentityConstMeta => const TaskConstMeta(
+            debugName: "import_identity",
+            argNames: ["secret"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  void crateApiSendmeInitLogging() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiIdentityInitIdentity({required String configDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(configDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiIdentityInitIdentityConstMeta,
+            argValues: [configDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIdentityInitIdentityConstMeta => const TaskConstMeta(
+            debugName: "init_identity",
+            argNames: ["configDir"],
+        );
+        
+
+@override void crateApiSendmeInitLogging()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSendmeInitLoggingConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSendmeInitLoggingConstMeta =>
-      const TaskConstMeta(debugName: "init_logging", argNames: []);
-
-  @override
-  Future<ReceiveResult> crateApiSendmeReceiveFile({required String ticket}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ticket, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSendmeInitLoggingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeInitLoggingConstMeta => const TaskConstMeta(
+            debugName: "init_logging",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiNodeInitNode({required NodeOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_node_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiNodeInitNodeConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNodeInitNodeConstMeta => const TaskConstMeta(
+            debugName: "init_node",
+            argNames: ["options"],
+        );
+        
+
+@override EndpointConfig crateApiSendmeLanOnlyEndpointConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_endpoint_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeLanOnlyEndpointConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeLanOnlyEndpointConfigConstMeta => const TaskConstMeta(
+            debugName: "lan_only_endpoint_config",
+            argNames: [],
+        );
+        
+
+@override Future<List<PartialDownload>> crateApiSendmeListPartialDownloads({String? tempDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(tempDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_partial_download,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeListPartialDownloadsConstMeta,
+            argValues: [tempDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeListPartialDownloadsConstMeta => const TaskConstMeta(
+            debugName: "list_partial_downloads",
+            argNames: ["tempDir"],
+        );
+        
+
+@override List<TransferInfo> crateApiSendmeListTransfers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_transfer_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeListTransfersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeListTransfersConstMeta => const TaskConstMeta(
+            debugName: "list_transfers",
+            argNames: [],
+        );
+        
+
+@override String crateApiIdentityNodeId()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIdentityNodeIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIdentityNodeIdConstMeta => const TaskConstMeta(
+            debugName: "node_id",
+            argNames: [],
+        );
+        
+
+@override TicketInfo crateApiSendmeParseTicket({required String ticket })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticket, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ticket_info,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeParseTicketConstMeta,
+            argValues: [ticket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeParseTicketConstMeta => const TaskConstMeta(
+            debugName: "parse_ticket",
+            argNames: ["ticket"],
+        );
+        
+
+@override Stream<ProgressInfo> crateApiSendmeProgressStream()  { 
+            final sink = RustStreamSink<ProgressInfo>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_progress_info_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeProgressStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSendmeProgressStreamConstMeta => const TaskConstMeta(
+            debugName: "progress_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<String> crateApiShortCodePublishShortCode({required BigInt transferId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(transferId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiShortCodePublishShortCodeConstMeta,
+            argValues: [transferId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiShortCodePublishShortCodeConstMeta => const TaskConstMeta(
+            debugName: "publish_short_code",
+            argNames: ["transferId"],
+        );
+        
+
+@override QrImage crateApiSendmeQrCode({required String data , required QrOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(data, serializer);
+sse_encode_box_autoadd_qr_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_qr_image,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeQrCodeConstMeta,
+            argValues: [data, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeQrCodeConstMeta => const TaskConstMeta(
+            debugName: "qr_code",
+            argNames: ["data", "options"],
+        );
+        
+
+@override Future<ReceiveResult> crateApiSendmeReceiveFile({required BigInt transferId , required String ticket , required ReceiveOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(transferId, serializer);
+sse_encode_String(ticket, serializer);
+sse_encode_box_autoadd_receive_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_receive_result,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSendmeReceiveFileConstMeta,
-        argValues: [ticket],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSendmeReceiveFileConstMeta =>
-      const TaskConstMeta(debugName: "receive_file", argNames: ["ticket"]);
-
-  @override
-  Future<SendResult> crateApiSendmeSendFile({required String path}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeReceiveFileConstMeta,
+            argValues: [transferId, ticket, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeReceiveFileConstMeta => const TaskConstMeta(
+            debugName: "receive_file",
+            argNames: ["transferId", "ticket", "options"],
+        );
+        
+
+@override Future<String> crateApiShortCodeResolveShortCode({required String code })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(code, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiShortCodeResolveShortCodeConstMeta,
+            argValues: [code],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiShortCodeResolveShortCodeConstMeta => const TaskConstMeta(
+            debugName: "resolve_short_code",
+            argNames: ["code"],
+        );
+        
+
+@override Future<String> crateApiIdentityRotateIdentity()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiIdentityRotateIdentityConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIdentityRotateIdentityConstMeta => const TaskConstMeta(
+            debugName: "rotate_identity",
+            argNames: [],
+        );
+        
+
+@override Stream<SendEvent> crateApiSendmeSendEventStream()  { 
+            final sink = RustStreamSink<SendEvent>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_send_event_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeSendEventStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSendmeSendEventStreamConstMeta => const TaskConstMeta(
+            debugName: "send_event_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<SendResult> crateApiSendmeSendFile({required String path , required SendOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_send_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_send_result,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeSendFileConstMeta,
+            argValues: [path, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeSendFileConstMeta => const TaskConstMeta(
+            debugName: "send_file",
+            argNames: ["path", "options"],
+        );
+        
+
+@override Future<SendResult> crateApiSendmeSendFiles({required List<String> paths , required SendOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(paths, serializer);
+sse_encode_box_autoadd_send_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_send_result,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiSendmeSendFileConstMeta,
-        argValues: [path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSendmeSendFileConstMeta =>
-      const TaskConstMeta(debugName: "send_file", argNames: ["path"]);
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  ReceiveResult dco_decode_receive_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ReceiveResult(
-      fileCount: dco_decode_u_64(arr[0]),
-      size: dco_decode_u_64(arr[1]),
-      durationMs: dco_decode_u_64(arr[2]),
-    );
-  }
-
-  @protected
-  SendResult dco_decode_send_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return SendResult(
-      ticket: dco_decode_String(arr[0]),
-      hash: dco_decode_String(arr[1]),
-      size: dco_decode_u_64(arr[2]),
-      fileCount: dco_decode_u_64(arr[3]),
-    );
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  ReceiveResult sse_decode_receive_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_fileCount = sse_decode_u_64(deserializer);
-    var var_size = sse_decode_u_64(deserializer);
-    var var_durationMs = sse_decode_u_64(deserializer);
-    return ReceiveResult(
-      fileCount: var_fileCount,
-      size: var_size,
-      durationMs: var_durationMs,
-    );
-  }
-
-  @protected
-  SendResult sse_decode_send_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ticket = sse_decode_String(deserializer);
-    var var_hash = sse_decode_String(deserializer);
-    var var_size = sse_decode_u_64(deserializer);
-    var var_fileCount = sse_decode_u_64(deserializer);
-    return SendResult(
-      ticket: var_ticket,
-      hash: var_hash,
-      size: var_size,
-      fileCount: var_fileCount,
-    );
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_receive_result(ReceiveResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.fileCount, serializer);
-    sse_encode_u_64(self.size, serializer);
-    sse_encode_u_64(self.durationMs, serializer);
-  }
-
-  @protected
-  void sse_encode_send_result(SendResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.ticket, serializer);
-    sse_encode_String(self.hash, serializer);
-    sse_encode_u_64(self.size, serializer);
-    sse_encode_u_64(self.fileCount, serializer);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeSendFilesConstMeta,
+            argValues: [paths, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeSendFilesConstMeta => const TaskConstMeta(
+            debugName: "send_files",
+            argNames: ["paths", "options"],
+        );
+        
+
+@override void crateApiShortCodeSetCodeResolver({required CodeResolverConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_code_resolver_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiShortCodeSetCodeResolverConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiShortCodeSetCodeResolverConstMeta => const TaskConstMeta(
+            debugName: "set_code_resolver",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiSendmeSetLocale({required MessageLocale locale })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_message_locale(locale, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSendmeSetLocaleConstMeta,
+            argValues: [locale],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeSetLocaleConstMeta => const TaskConstMeta(
+            debugName: "set_locale",
+            argNames: ["locale"],
+        );
+        
+
+@override Future<void> crateApiNodeShutdownNode()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiNodeShutdownNodeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNodeShutdownNodeConstMeta => const TaskConstMeta(
+            debugName: "shutdown_node",
+            argNames: [],
+        );
+        
+
+@override Future<StopSharingResult> crateApiSendmeStopSharing({required String ticketOrId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketOrId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stop_sharing_result,
+          decodeErrorData: sse_decode_sendme_error,
+        )
+        ,
+            constMeta: kCrateApiSendmeStopSharingConstMeta,
+            argValues: [ticketOrId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendmeStopSharingConstMeta => const TaskConstMeta(
+            debugName: "stop_sharing",
+            argNames: ["ticketOrId"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<ProgressInfo> dco_decode_StreamSink_progress_info_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<SendEvent> dco_decode_StreamSink_send_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CodeResolverConfig dco_decode_box_autoadd_code_resolver_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_code_resolver_config(raw); }
+
+@protected ConnectionInfo dco_decode_box_autoadd_connection_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_connection_info(raw); }
+
+@protected NodeOptions dco_decode_box_autoadd_node_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_node_options(raw); }
+
+@protected QrImage dco_decode_box_autoadd_qr_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_qr_image(raw); }
+
+@protected QrOptions dco_decode_box_autoadd_qr_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_qr_options(raw); }
+
+@protected ReceiveOptions dco_decode_box_autoadd_receive_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_receive_options(raw); }
+
+@protected SendOptions dco_decode_box_autoadd_send_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_send_options(raw); }
+
+@protected TicketWarning dco_decode_box_autoadd_ticket_warning(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ticket_warning(raw); }
+
+@protected TransferInfo dco_decode_box_autoadd_transfer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_transfer_info(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected CodeResolverConfig dco_decode_code_resolver_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return CodeResolverConfig_InProcess();
+case 1: return CodeResolverConfig_Http(baseUrl: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ConflictPolicy dco_decode_conflict_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConflictPolicy.values[raw as int]; }
+
+@protected ConnectionInfo dco_decode_connection_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ConnectionInfo(remoteNodeId: dco_decode_String(arr[0]),
+path: dco_decode_connection_path(arr[1]),
+directAddr: dco_decode_opt_String(arr[2]),
+relayUrl: dco_decode_opt_String(arr[3]),
+rttMs: dco_decode_opt_box_autoadd_u_64(arr[4]),); }
+
+@protected ConnectionPath dco_decode_connection_path(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionPath.values[raw as int]; }
+
+@protected EndpointConfig dco_decode_endpoint_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return EndpointConfig(relayMode: dco_decode_relay_mode_option(arr[0]),
+relayUrls: dco_decode_list_String(arr[1]),
+dnsDiscovery: dco_decode_bool(arr[2]),
+pkarrPublishing: dco_decode_bool(arr[3]),
+mdnsDiscovery: dco_decode_bool(arr[4]),
+bindAddr: dco_decode_opt_String(arr[5]),
+bindPort: dco_decode_opt_box_autoadd_u_16(arr[6]),
+lanOnly: dco_decode_bool(arr[7]),
+readyTimeoutMs: dco_decode_u_64(arr[8]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<PartialDownload> dco_decode_list_partial_download(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_partial_download).toList(); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<TransferFile> dco_decode_list_transfer_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_transfer_file).toList(); }
+
+@protected List<TransferInfo> dco_decode_list_transfer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_transfer_info).toList(); }
+
+@protected MessageLocale dco_decode_message_locale(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MessageLocale.values[raw as int]; }
+
+@protected NodeOptions dco_decode_node_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return NodeOptions(storeDir: dco_decode_opt_String(arr[0]),
+endpoint: dco_decode_endpoint_config(arr[1]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected ConnectionInfo? dco_decode_opt_box_autoadd_connection_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_connection_info(raw); }
+
+@protected QrImage? dco_decode_opt_box_autoadd_qr_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_qr_image(raw); }
+
+@protected QrOptions? dco_decode_opt_box_autoadd_qr_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_qr_options(raw); }
+
+@protected TicketWarning? dco_decode_opt_box_autoadd_ticket_warning(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ticket_warning(raw); }
+
+@protected TransferInfo? dco_decode_opt_box_autoadd_transfer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_transfer_info(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected PartialDownload dco_decode_partial_download(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PartialDownload(hash: dco_decode_String(arr[0]),
+ticket: dco_decode_opt_String(arr[1]),
+localBytes: dco_decode_u_64(arr[2]),
+path: dco_decode_String(arr[3]),); }
+
+@protected PathRejectReason dco_decode_path_reject_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PathRejectReason.values[raw as int]; }
+
+@protected ProgressInfo dco_decode_progress_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return ProgressInfo(transferId: dco_decode_u_64(arr[0]),
+operation: dco_decode_progress_operation(arr[1]),
+stage: dco_decode_progress_stage(arr[2]),
+current: dco_decode_u_64(arr[3]),
+total: dco_decode_u_64(arr[4]),
+fileCount: dco_decode_u_64(arr[5]),
+filesDone: dco_decode_u_64(arr[6]),
+fileName: dco_decode_opt_String(arr[7]),
+fileCurrent: dco_decode_u_64(arr[8]),
+fileTotal: dco_decode_u_64(arr[9]),
+bytesPerSecond: dco_decode_u_64(arr[10]),
+etaSecs: dco_decode_opt_box_autoadd_u_64(arr[11]),
+elapsedMs: dco_decode_u_64(arr[12]),
+connection: dco_decode_opt_box_autoadd_connection_info(arr[13]),
+message: dco_decode_String(arr[14]),); }
+
+@protected ProgressOperation dco_decode_progress_operation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProgressOperation.values[raw as int]; }
+
+@protected ProgressStage dco_decode_progress_stage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProgressStage.values[raw as int]; }
+
+@protected QrEcLevel dco_decode_qr_ec_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return QrEcLevel.values[raw as int]; }
+
+@protected QrFormat dco_decode_qr_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return QrFormat.values[raw as int]; }
+
+@protected QrImage dco_decode_qr_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return QrImage_Png(bytes: dco_decode_list_prim_u_8_strict(raw[1]),);
+case 1: return QrImage_Svg(markup: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected QrOptions dco_decode_qr_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return QrOptions(format: dco_decode_qr_format(arr[0]),
+ecLevel: dco_decode_qr_ec_level(arr[1]),
+moduleSize: dco_decode_u_32(arr[2]),); }
+
+@protected ReceiveOptions dco_decode_receive_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ReceiveOptions(outputDir: dco_decode_opt_String(arr[0]),
+tempDir: dco_decode_opt_String(arr[1]),
+conflictPolicy: dco_decode_conflict_policy(arr[2]),
+rawFileName: dco_decode_opt_String(arr[3]),); }
+
+@protected ReceiveResult dco_decode_receive_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ReceiveResult(transferId: dco_decode_u_64(arr[0]),
+fileCount: dco_decode_u_64(arr[1]),
+size: dco_decode_u_64(arr[2]),
+durationMs: dco_decode_u_64(arr[3]),
+averageBytesPerSecond: dco_decode_u_64(arr[4]),
+peakBytesPerSecond: dco_decode_u_64(arr[5]),
+connection: dco_decode_opt_box_autoadd_connection_info(arr[6]),); }
+
+@protected RelayModeOption dco_decode_relay_mode_option(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RelayModeOption.values[raw as int]; }
+
+@protected SendEvent dco_decode_send_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return SendEvent(transferId: dco_decode_u_64(arr[0]),
+kind: dco_decode_send_event_kind(arr[1]),
+connectionId: dco_decode_u_64(arr[2]),
+requestId: dco_decode_opt_box_autoadd_u_64(arr[3]),
+nodeId: dco_decode_opt_String(arr[4]),
+bytesSent: dco_decode_u_64(arr[5]),
+total: dco_decode_u_64(arr[6]),
+bytesPerSecond: dco_decode_u_64(arr[7]),
+etaSecs: dco_decode_opt_box_autoadd_u_64(arr[8]),
+elapsedMs: dco_decode_u_64(arr[9]),); }
+
+@protected SendEventKind dco_decode_send_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SendEventKind.values[raw as int]; }
+
+@protected SendOptions dco_decode_send_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SendOptions(raw: dco_decode_bool(arr[0]),
+qr: dco_decode_opt_box_autoadd_qr_options(arr[1]),); }
+
+@protected SendResult dco_decode_send_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SendResult(transferId: dco_decode_u_64(arr[0]),
+ticket: dco_decode_String(arr[1]),
+hash: dco_decode_String(arr[2]),
+size: dco_decode_u_64(arr[3]),
+fileCount: dco_decode_u_64(arr[4]),
+warning: dco_decode_opt_box_autoadd_ticket_warning(arr[5]),
+qr: dco_decode_opt_box_autoadd_qr_image(arr[6]),); }
+
+@protected SendmeError dco_decode_sendme_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SendmeError_InvalidTicket(reason: dco_decode_String(raw[1]),);
+case 1: return SendmeError_UnknownCode(code: dco_decode_String(raw[1]),);
+case 2: return SendmeError_InvalidIdentity(reason: dco_decode_String(raw[1]),);
+case 3: return SendmeError_InvalidConfig(reason: dco_decode_String(raw[1]),);
+case 4: return SendmeError_NodeNotStarted();
+case 5: return SendmeError_ConnectTimeout(nodeId: dco_decode_String(raw[1]),timeoutSecs: dco_decode_u_64(raw[2]),);
+case 6: return SendmeError_PeerUnreachable(nodeId: dco_decode_String(raw[1]),reason: dco_decode_String(raw[2]),);
+case 7: return SendmeError_TargetExists(path: dco_decode_String(raw[1]),);
+case 8: return SendmeError_Io(path: dco_decode_opt_String(raw[1]),message: dco_decode_String(raw[2]),);
+case 9: return SendmeError_StoreCorrupt(hash: dco_decode_String(raw[1]),message: dco_decode_String(raw[2]),);
+case 10: return SendmeError_Cancelled(transferId: dco_decode_u_64(raw[1]),);
+case 11: return SendmeError_PathRejected(name: dco_decode_String(raw[1]),reason: dco_decode_path_reject_reason(raw[2]),);
+case 12: return SendmeError_UnknownTransfer(idOrTicket: dco_decode_String(raw[1]),);
+case 13: return SendmeError_DownloadFailed(nodeId: dco_decode_String(raw[1]),reason: dco_decode_String(raw[2]),);
+case 14: return SendmeError_TransferFinished(transferId: dco_decode_u_64(raw[1]),state: dco_decode_transfer_state(raw[2]),);
+case 15: return SendmeError_TransferRunning(transferId: dco_decode_u_64(raw[1]),state: dco_decode_transfer_state(raw[2]),);
+case 16: return SendmeError_NoFreeShortCode(attempts: dco_decode_u_32(raw[1]),);
+case 17: return SendmeError_Other(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected StopSharingResult dco_decode_stop_sharing_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return StopSharingResult(transferId: dco_decode_u_64(arr[0]),
+connectedReceivers: dco_decode_u_64(arr[1]),); }
+
+@protected TicketFormat dco_decode_ticket_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TicketFormat.values[raw as int]; }
+
+@protected TicketInfo dco_decode_ticket_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return TicketInfo(nodeId: dco_decode_String(arr[0]),
+relayUrl: dco_decode_opt_String(arr[1]),
+directAddrs: dco_decode_list_String(arr[2]),
+hash: dco_decode_String(arr[3]),
+format: dco_decode_ticket_format(arr[4]),
+isCollection: dco_decode_bool(arr[5]),
+fileName: dco_decode_opt_String(arr[6]),); }
+
+@protected TicketWarning dco_decode_ticket_warning(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TicketWarning.values[raw as int]; }
+
+@protected TransferFile dco_decode_transfer_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TransferFile(name: dco_decode_String(arr[0]),
+size: dco_decode_u_64(arr[1]),); }
+
+@protected TransferInfo dco_decode_transfer_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return TransferInfo(id: dco_decode_u_64(arr[0]),
+kind: dco_decode_transfer_kind(arr[1]),
+state: dco_decode_transfer_state(arr[2]),
+ticket: dco_decode_opt_String(arr[3]),
+error: dco_decode_opt_String(arr[4]),
+files: dco_decode_list_transfer_file(arr[5]),
+shortCode: dco_decode_opt_String(arr[6]),); }
+
+@protected TransferKind dco_decode_transfer_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TransferKind.values[raw as int]; }
+
+@protected TransferState dco_decode_transfer_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TransferState.values[raw as int]; }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<ProgressInfo> sse_decode_StreamSink_progress_info_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<SendEvent> sse_decode_StreamSink_send_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected CodeResolverConfig sse_decode_box_autoadd_code_resolver_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_code_resolver_config(deserializer)); }
+
+@protected ConnectionInfo sse_decode_box_autoadd_connection_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_connection_info(deserializer)); }
+
+@protected NodeOptions sse_decode_box_autoadd_node_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_node_options(deserializer)); }
+
+@protected QrImage sse_decode_box_autoadd_qr_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_qr_image(deserializer)); }
+
+@protected QrOptions sse_decode_box_autoadd_qr_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_qr_options(deserializer)); }
+
+@protected ReceiveOptions sse_decode_box_autoadd_receive_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_receive_options(deserializer)); }
+
+@protected SendOptions sse_decode_box_autoadd_send_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_send_options(deserializer)); }
+
+@protected TicketWarning sse_decode_box_autoadd_ticket_warning(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ticket_warning(deserializer)); }
+
+@protected TransferInfo sse_decode_box_autoadd_transfer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_transfer_info(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected CodeResolverConfig sse_decode_code_resolver_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return CodeResolverConfig_InProcess();case 1: var var_baseUrl = sse_decode_String(deserializer);
+return CodeResolverConfig_Http(baseUrl: var_baseUrl); default: throw UnimplementedError(''); }
+             }
+
+@protected ConflictPolicy sse_decode_conflict_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConflictPolicy.values[inner]; }
+
+@protected ConnectionInfo sse_decode_connection_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_remoteNodeId = sse_decode_String(deserializer);
+var var_path = sse_decode_connection_path(deserializer);
+var var_directAddr = sse_decode_opt_String(deserializer);
+var var_relayUrl = sse_decode_opt_String(deserializer);
+var var_rttMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return ConnectionInfo(remoteNodeId: var_remoteNodeId, path: var_path, directAddr: var_directAddr, relayUrl: var_relayUrl, rttMs: var_rttMs); }
+
+@protected ConnectionPath sse_decode_connection_path(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionPath.values[inner]; }
+
+@protected EndpointConfig sse_decode_endpoint_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relayMode = sse_decode_relay_mode_option(deserializer);
+var var_relayUrls = sse_decode_list_String(deserializer);
+var var_dnsDiscovery = sse_decode_bool(deserializer);
+var var_pkarrPublishing = sse_decode_bool(deserializer);
+var var_mdnsDiscovery = sse_decode_bool(deserializer);
+var var_bindAddr = sse_decode_opt_String(deserializer);
+var var_bindPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_lanOnly = sse_decode_bool(deserializer);
+var var_readyTimeoutMs = sse_decode_u_64(deserializer);
+return EndpointConfig(relayMode: var_relayMode, relayUrls: var_relayUrls, dnsDiscovery: var_dnsDiscovery, pkarrPublishing: var_pkarrPublishing, mdnsDiscovery: var_mdnsDiscovery, bindAddr: var_bindAddr, bindPort: var_bindPort, lanOnly: var_lanOnly, readyTimeoutMs: var_readyTimeoutMs); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PartialDownload> sse_decode_list_partial_download(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PartialDownload>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_partial_download(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<TransferFile> sse_decode_list_transfer_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TransferFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_transfer_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TransferInfo> sse_decode_list_transfer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TransferInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_transfer_info(deserializer)); }
+        return ans_;
+         }
+
+@protected MessageLocale sse_decode_message_locale(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MessageLocale.values[inner]; }
+
+@protected NodeOptions sse_decode_node_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_storeDir = sse_decode_opt_String(deserializer);
+var var_endpoint = sse_decode_endpoint_config(deserializer);
+return NodeOptions(storeDir: var_storeDir, endpoint: var_endpoint); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ConnectionInfo? sse_decode_opt_box_autoadd_connection_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_connection_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected QrImage? sse_decode_opt_box_autoadd_qr_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_qr_image(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected QrOptions? sse_decode_opt_box_autoadd_qr_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_qr_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TicketWarning? sse_decode_opt_box_autoadd_ticket_warning(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ticket_warning(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TransferInfo? sse_decode_opt_box_autoadd_transfer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_transfer_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PartialDownload sse_decode_partial_download(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hash = sse_decode_String(deserializer);
+var var_ticket = sse_decode_opt_String(deserializer);
+var var_localBytes = sse_decode_u_64(deserializer);
+var var_path = sse_decode_String(deserializer);
+return PartialDownload(hash: var_hash, ticket: var_ticket, localBytes: var_localBytes, path: var_path); }
+
+@protected PathRejectReason sse_decode_path_reject_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PathRejectReason.values[inner]; }
+
+@protected ProgressInfo sse_decode_progress_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transferId = sse_decode_u_64(deserializer);
+var var_operation = sse_decode_progress_operation(deserializer);
+var var_stage = sse_decode_progress_stage(deserializer);
+var var_current = sse_decode_u_64(deserializer);
+var var_total = sse_decode_u_64(deserializer);
+var var_fileCount = sse_decode_u_64(deserializer);
+var var_filesDone = sse_decode_u_64(deserializer);
+var var_fileName = sse_decode_opt_String(deserializer);
+var var_fileCurrent = sse_decode_u_64(deserializer);
+var var_fileTotal = sse_decode_u_64(deserializer);
+var var_bytesPerSecond = sse_decode_u_64(deserializer);
+var var_etaSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_elapsedMs = sse_decode_u_64(deserializer);
+var var_connection = sse_decode_opt_box_autoadd_connection_info(deserializer);
+var var_message = sse_decode_String(deserializer);
+return ProgressInfo(transferId: var_transferId, operation: var_operation, stage: var_stage, current: var_current, total: var_total, fileCount: var_fileCount, filesDone: var_filesDone, fileName: var_fileName, fileCurrent: var_fileCurrent, fileTotal: var_fileTotal, bytesPerSecond: var_bytesPerSecond, etaSecs: var_etaSecs, elapsedMs: var_elapsedMs, connection: var_connection, message: var_message); }
+
+@protected ProgressOperation sse_decode_progress_operation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProgressOperation.values[inner]; }
+
+@protected ProgressStage sse_decode_progress_stage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProgressStage.values[inner]; }
+
+@protected QrEcLevel sse_decode_qr_ec_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return QrEcLevel.values[inner]; }
+
+@protected QrFormat sse_decode_qr_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return QrFormat.values[inner]; }
+
+@protected QrImage sse_decode_qr_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+return QrImage_Png(bytes: var_bytes);case 1: var var_markup = sse_decode_String(deserializer);
+return QrImage_Svg(markup: var_markup); default: throw UnimplementedError(''); }
+             }
+
+@protected QrOptions sse_decode_qr_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_format = sse_decode_qr_format(deserializer);
+var var_ecLevel = sse_decode_qr_ec_level(deserializer);
+var var_moduleSize = sse_decode_u_32(deserializer);
+return QrOptions(format: var_format, ecLevel: var_ecLevel, moduleSize: var_moduleSize); }
+
+@protected ReceiveOptions sse_decode_receive_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_outputDir = sse_decode_opt_String(deserializer);
+var var_tempDir = sse_decode_opt_String(deserializer);
+var var_conflictPolicy = sse_decode_conflict_policy(deserializer);
+var var_rawFileName = sse_decode_opt_String(deserializer);
+return ReceiveOptions(outputDir: var_outputDir, tempDir: var_tempDir, conflictPolicy: var_conflictPolicy, rawFileName: var_rawFileName); }
+
+@protected ReceiveResult sse_decode_receive_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transferId = sse_decode_u_64(deserializer);
+var var_fileCount = sse_decode_u_64(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+var var_averageBytesPerSecond = sse_decode_u_64(deserializer);
+var var_peakBytesPerSecond = sse_decode_u_64(deserializer);
+var var_connection = sse_decode_opt_box_autoadd_connection_info(deserializer);
+return ReceiveResult(transferId: var_transferId, fileCount: var_fileCount, size: var_size, durationMs: var_durationMs, averageBytesPerSecond: var_averageBytesPerSecond, peakBytesPerSecond: var_peakBytesPerSecond, connection: var_connection); }
+
+@protected RelayModeOption sse_decode_relay_mode_option(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RelayModeOption.values[inner]; }
+
+@protected SendEvent sse_decode_send_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transferId = sse_decode_u_64(deserializer);
+var var_kind = sse_decode_send_event_kind(deserializer);
+var var_connectionId = sse_decode_u_64(deserializer);
+var var_requestId = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_nodeId = sse_decode_opt_String(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_total = sse_decode_u_64(deserializer);
+var var_bytesPerSecond = sse_decode_u_64(deserializer);
+var var_etaSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_elapsedMs = sse_decode_u_64(deserializer);
+return SendEvent(transferId: var_transferId, kind: var_kind, connectionId: var_connectionId, requestId: var_requestId, nodeId: var_nodeId, bytesSent: var_bytesSent, total: var_total, bytesPerSecond: var_bytesPerSecond, etaSecs: var_etaSecs, elapsedMs: var_elapsedMs); }
+
+@protected SendEventKind sse_decode_send_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SendEventKind.values[inner]; }
+
+@protected SendOptions sse_decode_send_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_raw = sse_decode_bool(deserializer);
+var var_qr = sse_decode_opt_box_autoadd_qr_options(deserializer);
+return SendOptions(raw: var_raw, qr: var_qr); }
+
+@protected SendResult sse_decode_send_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transferId = sse_decode_u_64(deserializer);
+var var_ticket = sse_decode_String(deserializer);
+var var_hash = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_fileCount = sse_decode_u_64(deserializer);
+var var_warning = sse_decode_opt_box_autoadd_ticket_warning(deserializer);
+var var_qr = sse_decode_opt_box_autoadd_qr_image(deserializer);
+return SendResult(transferId: var_transferId, ticket: var_ticket, hash: var_hash, size: var_size, fileCount: var_fileCount, warning: var_warning, qr: var_qr); }
+
+@protected SendmeError sse_decode_sendme_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_reason = sse_decode_String(deserializer);
+return SendmeError_InvalidTicket(reason: var_reason);case 1: var var_code = sse_decode_String(deserializer);
+return SendmeError_UnknownCode(code: var_code);case 2: var var_reason = sse_decode_String(deserializer);
+return SendmeError_InvalidIdentity(reason: var_reason);case 3: var var_reason = sse_decode_String(deserializer);
+return SendmeError_InvalidConfig(reason: var_reason);case 4: return SendmeError_NodeNotStarted();case 5: var var_nodeId = sse_decode_String(deserializer);
+var var_timeoutSecs = sse_decode_u_64(deserializer);
+return SendmeError_ConnectTimeout(nodeId: var_nodeId, timeoutSecs: var_timeoutSecs);case 6: var var_nodeId = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return SendmeError_PeerUnreachable(nodeId: var_nodeId, reason: var_reason);case 7: var var_path = sse_decode_String(deserializer);
+return SendmeError_TargetExists(path: var_path);case 8: var var_path = sse_decode_opt_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return SendmeError_Io(path: var_path, message: var_message);case 9: var var_hash = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return SendmeError_StoreCorrupt(hash: var_hash, message: var_message);case 10: var var_transferId = sse_decode_u_64(deserializer);
+return SendmeError_Cancelled(transferId: var_transferId);case 11: var var_name = sse_decode_String(deserializer);
+var var_reason = sse_decode_path_reject_reason(deserializer);
+return SendmeError_PathRejected(name: var_name, reason: var_reason);case 12: var var_idOrTicket = sse_decode_String(deserializer);
+return SendmeError_UnknownTransfer(idOrTicket: var_idOrTicket);case 13: var var_nodeId = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return SendmeError_DownloadFailed(nodeId: var_nodeId, reason: var_reason);case 14: var var_transferId = sse_decode_u_64(deserializer);
+var var_state = sse_decode_transfer_state(deserializer);
+return SendmeError_TransferFinished(transferId: var_transferId, state: var_state);case 15: var var_transferId = sse_decode_u_64(deserializer);
+var var_state = sse_decode_transfer_state(deserializer);
+return SendmeError_TransferRunning(transferId: var_transferId, state: var_state);case 16: var var_attempts = sse_decode_u_32(deserializer);
+return SendmeError_NoFreeShortCode(attempts: var_attempts);case 17: var var_message = sse_decode_String(deserializer);
+return SendmeError_Other(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected StopSharingResult sse_decode_stop_sharing_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transferId = sse_decode_u_64(deserializer);
+var var_connectedReceivers = sse_decode_u_64(deserializer);
+return StopSharingResult(transferId: var_transferId, connectedReceivers: var_connectedReceivers); }
+
+@protected TicketFormat sse_decode_ticket_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TicketFormat.values[inner]; }
+
+@protected TicketInfo sse_decode_ticket_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_relayUrl = sse_decode_opt_String(deserializer);
+var var_directAddrs = sse_decode_list_String(deserializer);
+var var_hash = sse_decode_String(deserializer);
+var var_format = sse_decode_ticket_format(deserializer);
+var var_isCollection = sse_decode_bool(deserializer);
+var var_fileName = sse_decode_opt_String(deserializer);
+return TicketInfo(nodeId: var_nodeId, relayUrl: var_relayUrl, directAddrs: var_directAddrs, hash: var_hash, format: var_format, isCollection: var_isCollection, fileName: var_fileName); }
+
+@protected TicketWarning sse_decode_ticket_warning(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TicketWarning.values[inner]; }
+
+@protected TransferFile sse_decode_transfer_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+return TransferFile(name: var_name, size: var_size); }
+
+@protected TransferInfo sse_decode_transfer_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_64(deserializer);
+var var_kind = sse_decode_transfer_kind(deserializer);
+var var_state = sse_decode_transfer_state(deserializer);
+var var_ticket = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_files = sse_decode_list_transfer_file(deserializer);
+var var_shortCode = sse_decode_opt_String(deserializer);
+return TransferInfo(id: var_id, kind: var_kind, state: var_state, ticket: var_ticket, error: var_error, files: var_files, shortCode: var_shortCode); }
+
+@protected TransferKind sse_decode_transfer_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TransferKind.values[inner]; }
+
+@protected TransferState sse_decode_transfer_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TransferState.values[inner]; }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_progress_info_Sse(RustStreamSink<ProgressInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_progress_info,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_send_event_Sse(RustStreamSink<SendEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_send_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_code_resolver_config(CodeResolverConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_code_resolver_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_connection_info(ConnectionInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_connection_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_node_options(NodeOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_node_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_qr_image(QrImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_qr_image(self, serializer); }
+
+@protected void sse_encode_box_autoadd_qr_options(QrOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_qr_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_receive_options(ReceiveOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_receive_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_send_options(SendOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_send_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ticket_warning(TicketWarning self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ticket_warning(self, serializer); }
+
+@protected void sse_encode_box_autoadd_transfer_info(TransferInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_transfer_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_code_resolver_config(CodeResolverConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case CodeResolverConfig_InProcess(): sse_encode_i_32(0, serializer); case CodeResolverConfig_Http(baseUrl: final baseUrl): sse_encode_i_32(1, serializer); sse_encode_String(baseUrl, serializer);
+  } }
+
+@protected void sse_encode_conflict_policy(ConflictPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_connection_info(ConnectionInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.remoteNodeId, serializer);
+sse_encode_connection_path(self.path, serializer);
+sse_encode_opt_String(self.directAddr, serializer);
+sse_encode_opt_String(self.relayUrl, serializer);
+sse_encode_opt_box_autoadd_u_64(self.rttMs, serializer);
+ }
+
+@protected void sse_encode_connection_path(ConnectionPath self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_endpoint_config(EndpointConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_relay_mode_option(self.relayMode, serializer);
+sse_encode_list_String(self.relayUrls, serializer);
+sse_encode_bool(self.dnsDiscovery, serializer);
+sse_encode_bool(self.pkarrPublishing, serializer);
+sse_encode_bool(self.mdnsDiscovery, serializer);
+sse_encode_opt_String(self.bindAddr, serializer);
+sse_encode_opt_box_autoadd_u_16(self.bindPort, serializer);
+sse_encode_bool(self.lanOnly, serializer);
+sse_encode_u_64(self.readyTimeoutMs, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_partial_download(List<PartialDownload> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_partial_download(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_transfer_file(List<TransferFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_transfer_file(item, serializer); } }
+
+@protected void sse_encode_list_transfer_info(List<TransferInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_transfer_info(item, serializer); } }
+
+@protected void sse_encode_message_locale(MessageLocale self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_node_options(NodeOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.storeDir, serializer);
+sse_encode_endpoint_config(self.endpoint, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_connection_info(ConnectionInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_connection_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_qr_image(QrImage? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_qr_image(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_qr_options(QrOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_qr_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ticket_warning(TicketWarning? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ticket_warning(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_transfer_info(TransferInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_transfer_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_partial_download(PartialDownload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.hash, serializer);
+sse_encode_opt_String(self.ticket, serializer);
+sse_encode_u_64(self.localBytes, serializer);
+sse_encode_String(self.path, serializer);
+ }
+
+@protected void sse_encode_path_reject_reason(PathRejectReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_progress_info(ProgressInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.transferId, serializer);
+sse_encode_progress_operation(self.operation, serializer);
+sse_encode_progress_stage(self.stage, serializer);
+sse_encode_u_64(self.current, serializer);
+sse_encode_u_64(self.total, serializer);
+sse_encode_u_64(self.fileCount, serializer);
+sse_encode_u_64(self.filesDone, serializer);
+sse_encode_opt_String(self.fileName, serializer);
+sse_encode_u_64(self.fileCurrent, serializer);
+sse_encode_u_64(self.fileTotal, serializer);
+sse_encode_u_64(self.bytesPerSecond, serializer);
+sse_encode_opt_box_autoadd_u_64(self.etaSecs, serializer);
+sse_encode_u_64(self.elapsedMs, serializer);
+sse_encode_opt_box_autoadd_connection_info(self.connection, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_progress_operation(ProgressOperation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_progress_stage(ProgressStage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_qr_ec_level(QrEcLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_qr_format(QrFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_qr_image(QrImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case QrImage_Png(bytes: final bytes): sse_encode_i_32(0, serializer); sse_encode_list_prim_u_8_strict(bytes, serializer);
+case QrImage_Svg(markup: final markup): sse_encode_i_32(1, serializer); sse_encode_String(markup, serializer);
+  } }
+
+@protected void sse_encode_qr_options(QrOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_qr_format(self.format, serializer);
+sse_encode_qr_ec_level(self.ecLevel, serializer);
+sse_encode_u_32(self.moduleSize, serializer);
+ }
+
+@protected void sse_encode_receive_options(ReceiveOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.outputDir, serializer);
+sse_encode_opt_String(self.tempDir, serializer);
+sse_encode_conflict_policy(self.conflictPolicy, serializer);
+sse_encode_opt_String(self.rawFileName, serializer);
+ }
+
+@protected void sse_encode_receive_result(ReceiveResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.transferId, serializer);
+sse_encode_u_64(self.fileCount, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+sse_encode_u_64(self.averageBytesPerSecond, serializer);
+sse_encode_u_64(self.peakBytesPerSecond, serializer);
+sse_encode_opt_box_autoadd_connection_info(self.connection, serializer);
+ }
+
+@protected void sse_encode_relay_mode_option(RelayModeOption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_send_event(SendEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.transferId, serializer);
+sse_encode_send_event_kind(self.kind, serializer);
+sse_encode_u_64(self.connectionId, serializer);
+sse_encode_opt_box_autoadd_u_64(self.requestId, serializer);
+sse_encode_opt_String(self.nodeId, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.total, serializer);
+sse_encode_u_64(self.bytesPerSecond, serializer);
+sse_encode_opt_box_autoadd_u_64(self.etaSecs, serializer);
+sse_encode_u_64(self.elapsedMs, serializer);
+ }
+
+@protected void sse_encode_send_event_kind(SendEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_send_options(SendOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.raw, serializer);
+sse_encode_opt_box_autoadd_qr_options(self.qr, serializer);
+ }
+
+@protected void sse_encode_send_result(SendResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.transferId, serializer);
+sse_encode_String(self.ticket, serializer);
+sse_encode_String(self.hash, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_u_64(self.fileCount, serializer);
+sse_encode_opt_box_autoadd_ticket_warning(self.warning, serializer);
+sse_encode_opt_box_autoadd_qr_image(self.qr, serializer);
+ }
+
+@protected void sse_encode_sendme_error(SendmeError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SendmeError_InvalidTicket(reason: final reason): sse_encode_i_32(0, serializer); sse_encode_String(reason, serializer);
+case SendmeError_UnknownCode(code: final code): sse_encode_i_32(1, serializer); sse_encode_String(code, serializer);
+case SendmeError_InvalidIdentity(reason: final reason): sse_encode_i_32(2, serializer); sse_encode_String(reason, serializer);
+case SendmeError_InvalidConfig(reason: final reason): sse_encode_i_32(3, serializer); sse_encode_String(reason, serializer);
+case SendmeError_NodeNotStarted(): sse_encode_i_32(4, serializer); case SendmeError_ConnectTimeout(nodeId: final nodeId,timeoutSecs: final timeoutSecs): sse_encode_i_32(5, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_u_64(timeoutSecs, serializer);
+case SendmeError_PeerUnreachable(nodeId: final nodeId,reason: final reason): sse_encode_i_32(6, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(reason, serializer);
+case SendmeError_TargetExists(path: final path): sse_encode_i_32(7, serializer); sse_encode_String(path, serializer);
+case SendmeError_Io(path: final path,message: final message): sse_encode_i_32(8, serializer); sse_encode_opt_String(path, serializer);
+sse_encode_String(message, serializer);
+case SendmeError_StoreCorrupt(hash: final hash,message: final message): sse_encode_i_32(9, serializer); sse_encode_String(hash, serializer);
+sse_encode_String(message, serializer);
+case SendmeError_Cancelled(transferId: final transferId): sse_encode_i_32(10, serializer); sse_encode_u_64(transferId, serializer);
+case SendmeError_PathRejected(name: final name,reason: final reason): sse_encode_i_32(11, serializer); sse_encode_String(name, serializer);
+sse_encode_path_reject_reason(reason, serializer);
+case SendmeError_UnknownTransfer(idOrTicket: final idOrTicket): sse_encode_i_32(12, serializer); sse_encode_String(idOrTicket, serializer);
+case SendmeError_DownloadFailed(nodeId: final nodeId,reason: final reason): sse_encode_i_32(13, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(reason, serializer);
+case SendmeError_TransferFinished(transferId: final transferId,state: final state): sse_encode_i_32(14, serializer); sse_encode_u_64(transferId, serializer);
+sse_encode_transfer_state(state, serializer);
+case SendmeError_TransferRunning(transferId: final transferId,state: final state): sse_encode_i_32(15, serializer); sse_encode_u_64(transferId, serializer);
+sse_encode_transfer_state(state, serializer);
+case SendmeError_NoFreeShortCode(attempts: final attempts): sse_encode_i_32(16, serializer); sse_encode_u_32(attempts, serializer);
+case SendmeError_Other(message: final message): sse_encode_i_32(17, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_stop_sharing_result(StopSharingResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.transferId, serializer);
+sse_encode_u_64(self.connectedReceivers, serializer);
+ }
+
+@protected void sse_encode_ticket_format(TicketFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ticket_info(TicketInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_opt_String(self.relayUrl, serializer);
+sse_encode_list_String(self.directAddrs, serializer);
+sse_encode_String(self.hash, serializer);
+sse_encode_ticket_format(self.format, serializer);
+sse_encode_bool(self.isCollection, serializer);
+sse_encode_opt_String(self.fileName, serializer);
+ }
+
+@protected void sse_encode_ticket_warning(TicketWarning self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_transfer_file(TransferFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.size, serializer);
+ }
+
+@protected void sse_encode_transfer_info(TransferInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.id, serializer);
+sse_encode_transfer_kind(self.kind, serializer);
+sse_encode_transfer_state(self.state, serializer);
+sse_encode_opt_String(self.ticket, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_list_transfer_file(self.files, serializer);
+sse_encode_opt_String(self.shortCode, serializer);
+ }
+
+@protected void sse_encode_transfer_kind(TransferKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_transfer_state(TransferState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+                }
+                
@@ -3,125 +3,454 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/identity.dart';
+import 'api/node.dart';
 import 'api/sendme.dart';
+import 'api/short_code.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
+import 'entry_names.dart';
+import 'error.dart';
 import 'frb_generated.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  ReceiveResult dco_decode_receive_result(dynamic raw);
+                  
 
-  @protected
-  SendResult dco_decode_send_result(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected RustStreamSink<ProgressInfo> dco_decode_StreamSink_progress_info_Sse(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected RustStreamSink<SendEvent> dco_decode_StreamSink_send_event_Sse(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected CodeResolverConfig dco_decode_box_autoadd_code_resolver_config(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected ConnectionInfo dco_decode_box_autoadd_connection_info(dynamic raw);
 
-  @protected
-  ReceiveResult sse_decode_receive_result(SseDeserializer deserializer);
+@protected NodeOptions dco_decode_box_autoadd_node_options(dynamic raw);
 
-  @protected
-  SendResult sse_decode_send_result(SseDeserializer deserializer);
+@protected QrImage dco_decode_box_autoadd_qr_image(dynamic raw);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected QrOptions dco_decode_box_autoadd_qr_options(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected ReceiveOptions dco_decode_box_autoadd_receive_options(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected SendOptions dco_decode_box_autoadd_send_options(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected TicketWarning dco_decode_box_autoadd_ticket_warning(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected TransferInfo dco_decode_box_autoadd_transfer_info(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected CodeResolverConfig dco_decode_code_resolver_config(dynamic raw);
 
-  @protected
-  void sse_encode_receive_result(ReceiveResult self, SseSerializer serializer);
+@protected ConflictPolicy dco_decode_conflict_policy(dynamic raw);
 
-  @protected
-  void sse_encode_send_result(SendResult self, SseSerializer serializer);
+@protected ConnectionInfo dco_decode_connection_info(dynamic raw);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected ConnectionPath dco_decode_connection_path(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected EndpointConfig dco_decode_endpoint_config(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<PartialDownload> dco_decode_list_partial_download(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<TransferFile> dco_decode_list_transfer_file(dynamic raw);
+
+@protected List<TransferInfo> dco_decode_list_transfer_info(dynamic raw);
+
+@protected MessageLocale dco_decode_message_locale(dynamic raw);
+
+@protected NodeOptions dco_decode_node_options(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected ConnectionInfo? dco_decode_opt_box_autoadd_connection_info(dynamic raw);
+
+@protected QrImage? dco_decode_opt_box_autoadd_qr_image(dynamic raw);
+
+@protected QrOptions? dco_decode_opt_box_autoadd_qr_options(dynamic raw);
+
+@protected TicketWarning? dco_decode_opt_box_autoadd_ticket_warning(dynamic raw);
+
+@protected TransferInfo? dco_decode_opt_box_autoadd_transfer_info(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected PartialDownload dco_decode_partial_download(dynamic raw);
+
+@protected PathRejectReason dco_decode_path_reject_reason(dynamic raw);
+
+@protected ProgressInfo dco_decode_progress_info(dynamic raw);
+
+@protected ProgressOperation dco_decode_progress_operation(dynamic raw);
+
+@protected ProgressStage dco_decode_progress_stage(dynamic raw);
+
+@protected QrEcLevel dco_decode_qr_ec_level(dynamic raw);
+
+@protected QrFormat dco_decode_qr_format(dynamic raw);
+
+@protected QrImage dco_decode_qr_image(dynamic raw);
+
+@protected QrOptions dco_decode_qr_options(dynamic raw);
+
+@protected ReceiveOptions dco_decode_receive_options(dynamic raw);
+
+@protected ReceiveResult dco_decode_receive_result(dynamic raw);
+
+@protected RelayModeOption dco_decode_relay_mode_option(dynamic raw);
+
+@protected SendEvent dco_decode_send_event(dynamic raw);
+
+@protected SendEventKind dco_decode_send_event_kind(dynamic raw);
+
+@protected SendOptions dco_decode_send_options(dynamic raw);
+
+@protected SendResult dco_decode_send_result(dynamic raw);
+
+@protected SendmeError dco_decode_sendme_error(dynamic raw);
+
+@protected StopSharingResult dco_decode_stop_sharing_result(dynamic raw);
+
+@protected TicketFormat dco_decode_ticket_format(dynamic raw);
+
+@protected TicketInfo dco_decode_ticket_info(dynamic raw);
+
+@protected TicketWarning dco_decode_ticket_warning(dynamic raw);
+
+@protected TransferFile dco_decode_transfer_file(dynamic raw);
+
+@protected TransferInfo dco_decode_transfer_info(dynamic raw);
+
+@protected TransferKind dco_decode_transfer_kind(dynamic raw);
+
+@protected TransferState dco_decode_transfer_state(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<ProgressInfo> sse_decode_StreamSink_progress_info_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<SendEvent> sse_decode_StreamSink_send_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected CodeResolverConfig sse_decode_box_autoadd_code_resolver_config(SseDeserializer deserializer);
+
+@protected ConnectionInfo sse_decode_box_autoadd_connection_info(SseDeserializer deserializer);
+
+@protected NodeOptions sse_decode_box_autoadd_node_options(SseDeserializer deserializer);
+
+@protected QrImage sse_decode_box_autoadd_qr_image(SseDeserializer deserializer);
+
+@protected QrOptions sse_decode_box_autoadd_qr_options(SseDeserializer deserializer);
+
+@protected ReceiveOptions sse_decode_box_autoadd_receive_options(SseDeserializer deserializer);
+
+@protected SendOptions sse_decode_box_autoadd_send_options(SseDeserializer deserializer);
+
+@protected TicketWarning sse_decode_box_autoadd_ticket_warning(SseDeserializer deserializer);
+
+@protected TransferInfo sse_decode_box_autoadd_transfer_info(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected CodeResolverConfig sse_decode_code_resolver_config(SseDeserializer deserializer);
+
+@protected ConflictPolicy sse_decode_conflict_policy(SseDeserializer deserializer);
+
+@protected ConnectionInfo sse_decode_connection_info(SseDeserializer deserializer);
+
+@protected ConnectionPath sse_decode_connection_path(SseDeserializer deserializer);
+
+@protected EndpointConfig sse_decode_endpoint_config(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<PartialDownload> sse_decode_list_partial_download(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<TransferFile> sse_decode_list_transfer_file(SseDeserializer deserializer);
+
+@protected List<TransferInfo> sse_decode_list_transfer_info(SseDeserializer deserializer);
+
+@protected MessageLocale sse_decode_message_locale(SseDeserializer deserializer);
+
+@protected NodeOptions sse_decode_node_options(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected ConnectionInfo? sse_decode_opt_box_autoadd_connection_info(SseDeserializer deserializer);
+
+@protected QrImage? sse_decode_opt_box_autoadd_qr_image(SseDeserializer deserializer);
+
+@protected QrOptions? sse_decode_opt_box_autoadd_qr_options(SseDeserializer deserializer);
+
+@protected TicketWarning? sse_decode_opt_box_autoadd_ticket_warning(SseDeserializer deserializer);
+
+@protected TransferInfo? sse_decode_opt_box_autoadd_transfer_info(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected PartialDownload sse_decode_partial_download(SseDeserializer deserializer);
+
+@protected PathRejectReason sse_decode_path_reject_reason(SseDeserializer deserializer);
+
+@protected ProgressInfo sse_decode_progress_info(SseDeserializer deserializer);
+
+@protected ProgressOperation sse_decode_progress_operation(SseDeserializer deserializer);
+
+@protected ProgressStage sse_decode_progress_stage(SseDeserializer deserializer);
+
+@protected QrEcLevel sse_decode_qr_ec_level(SseDeserializer deserializer);
+
+@protected QrFormat sse_decode_qr_format(SseDeserializer deserializer);
+
+@protected QrImage sse_decode_qr_image(SseDeserializer deserializer);
+
+@protected QrOptions sse_decode_qr_options(SseDeserializer deserializer);
+
+@protected ReceiveOptions sse_decode_receive_options(SseDeserializer deserializer);
+
+@protected ReceiveResult sse_decode_receive_result(SseDeserializer deserializer);
+
+@protected RelayModeOption sse_decode_relay_mode_option(SseDeserializer deserializer);
+
+@protected SendEvent sse_decode_send_event(SseDeserializer deserializer);
+
+@protected SendEventKind sse_decode_send_event_kind(SseDeserializer deserializer);
+
+@protected SendOptions sse_decode_send_options(SseDeserializer deserializer);
+
+@protected SendResult sse_decode_send_result(SseDeserializer deserializer);
+
+@protected SendmeError sse_decode_sendme_error(SseDeserializer deserializer);
+
+@protected StopSharingResult sse_decode_stop_sharing_result(SseDeserializer deserializer);
+
+@protected TicketFormat sse_decode_ticket_format(SseDeserializer deserializer);
+
+@protected TicketInfo sse_decode_ticket_info(SseDeserializer deserializer);
+
+@protected TicketWarning sse_decode_ticket_warning(SseDeserializer deserializer);
+
+@protected TransferFile sse_decode_transfer_file(SseDeserializer deserializer);
+
+@protected TransferInfo sse_decode_transfer_info(SseDeserializer deserializer);
+
+@protected TransferKind sse_decode_transfer_kind(SseDeserializer deserializer);
+
+@protected TransferState sse_decode_transfer_state(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_progress_info_Sse(RustStreamSink<ProgressInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_send_event_Sse(RustStreamSink<SendEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_code_resolver_config(CodeResolverConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_connection_info(ConnectionInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_node_options(NodeOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_qr_image(QrImage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_qr_options(QrOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_receive_options(ReceiveOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_send_options(SendOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ticket_warning(TicketWarning self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_transfer_info(TransferInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_code_resolver_config(CodeResolverConfig self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_policy(ConflictPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_connection_info(ConnectionInfo self, SseSerializer serializer);
+
+@protected void sse_encode_connection_path(ConnectionPath self, SseSerializer serializer);
+
+@protected void sse_encode_endpoint_config(EndpointConfig self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_partial_download(List<PartialDownload> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_transfer_file(List<TransferFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_transfer_info(List<TransferInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_message_locale(MessageLocale self, SseSerializer serializer);
+
+@protected void sse_encode_node_options(NodeOptions self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_connection_info(ConnectionInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_qr_image(QrImage? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_qr_options(QrOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_ticket_warning(TicketWarning? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_transfer_info(TransferInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_partial_download(PartialDownload self, SseSerializer serializer);
+
+@protected void sse_encode_path_reject_reason(PathRejectReason self, SseSerializer serializer);
+
+@protected void sse_encode_progress_info(ProgressInfo self, SseSerializer serializer);
+
+@protected void sse_encode_progress_operation(ProgressOperation self, SseSerializer serializer);
+
+@protected void sse_encode_progress_stage(ProgressStage self, SseSerializer serializer);
+
+@protected void sse_encode_qr_ec_level(QrEcLevel self, SseSerializer serializer);
+
+@protected void sse_encode_qr_format(QrFormat self, SseSerializer serializer);
+
+@protected void sse_encode_qr_image(QrImage self, SseSerializer serializer);
+
+@protected void sse_encode_qr_options(QrOptions self, SseSerializer serializer);
+
+@protected void sse_encode_receive_options(ReceiveOptions self, SseSerializer serializer);
+
+@protected void sse_encode_receive_result(ReceiveResult self, SseSerializer serializer);
+
+@protected void sse_encode_relay_mode_option(RelayModeOption self, SseSerializer serializer);
+
+@protected void sse_encode_send_event(SendEvent self, SseSerializer serializer);
+
+@protected void sse_encode_send_event_kind(SendEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_send_options(SendOptions self, SseSerializer serializer);
+
+@protected void sse_encode_send_result(SendResult self, SseSerializer serializer);
+
+@protected void sse_encode_sendme_error(SendmeError self, SseSerializer serializer);
+
+@protected void sse_encode_stop_sharing_result(StopSharingResult self, SseSerializer serializer);
+
+@protected void sse_encode_ticket_format(TicketFormat self, SseSerializer serializer);
+
+@protected void sse_encode_ticket_info(TicketInfo self, SseSerializer serializer);
+
+@protected void sse_encode_ticket_warning(TicketWarning self, SseSerializer serializer);
+
+@protected void sse_encode_transfer_file(TransferFile self, SseSerializer serializer);
+
+@protected void sse_encode_transfer_info(TransferInfo self, SseSerializer serializer);
+
+@protected void sse_encode_transfer_kind(TransferKind self, SseSerializer serializer);
+
+@protected void sse_encode_transfer_state(TransferState self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-}
+            
+        }
+        
\ No newline at end of file
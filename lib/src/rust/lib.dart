@@ -5,55 +5,576 @@
 
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'lib.freezed.dart';
 
-class ReceiveResult {
-  final BigInt fileCount;
-  final BigInt size;
-  final BigInt durationMs;
-
-  const ReceiveResult({
-    required this.fileCount,
-    required this.size,
-    required this.durationMs,
-  });
-
-  @override
-  int get hashCode => fileCount.hashCode ^ size.hashCode ^ durationMs.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ReceiveResult &&
-          runtimeType == other.runtimeType &&
-          fileCount == other.fileCount &&
-          size == other.size &&
-          durationMs == other.durationMs;
-}
-
-class SendResult {
-  final String ticket;
-  final String hash;
-  final BigInt size;
-  final BigInt fileCount;
-
-  const SendResult({
-    required this.ticket,
-    required this.hash,
-    required this.size,
-    required this.fileCount,
-  });
-
-  @override
-  int get hashCode =>
-      ticket.hashCode ^ hash.hashCode ^ size.hashCode ^ fileCount.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SendResult &&
-          runtimeType == other.runtimeType &&
-          ticket == other.ticket &&
-          hash == other.hash &&
-          size == other.size &&
-          fileCount == other.fileCount;
-}
+            
+
+            
+
+            @freezed
+                sealed class CodeResolverConfig with _$CodeResolverConfig  {
+                    const CodeResolverConfig._();
+
+                     const factory CodeResolverConfig.inProcess() = CodeResolverConfig_InProcess;
+ const factory CodeResolverConfig.http({   required String baseUrl , }) = CodeResolverConfig_Http;
+
+                    
+
+                    
+                }
+
+enum ConflictPolicy {
+                    fail,
+overwrite,
+skip,
+renameWithSuffix,
+skipIfIdenticalHash,
+                    ;
+                    
+                }
+
+class ConnectionInfo  {
+                final String remoteNodeId;
+final ConnectionPath path;
+final String? directAddr;
+final String? relayUrl;
+final BigInt? rttMs;
+
+                const ConnectionInfo({required this.remoteNodeId ,required this.path ,this.directAddr ,this.relayUrl ,this.rttMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => remoteNodeId.hashCode^path.hashCode^directAddr.hashCode^relayUrl.hashCode^rttMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConnectionInfo &&
+                runtimeType == other.runtimeType
+                && remoteNodeId == other.remoteNodeId&& path == other.path&& directAddr == other.directAddr&& relayUrl == other.relayUrl&& rttMs == other.rttMs;
+        
+            }
+
+enum ConnectionPath {
+                    none,
+direct,
+relay,
+mixed,
+                    ;
+                    
+                }
+
+class EndpointConfig  {
+                final RelayModeOption relayMode;
+final List<String> relayUrls;
+final bool dnsDiscovery;
+final bool pkarrPublishing;
+final bool mdnsDiscovery;
+final String? bindAddr;
+final int? bindPort;
+final bool lanOnly;
+final BigInt readyTimeoutMs;
+
+                const EndpointConfig({required this.relayMode ,required this.relayUrls ,required this.dnsDiscovery ,required this.pkarrPublishing ,required this.mdnsDiscovery ,this.bindAddr ,this.bindPort ,required this.lanOnly ,required this.readyTimeoutMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => relayMode.hashCode^relayUrls.hashCode^dnsDiscovery.hashCode^pkarrPublishing.hashCode^mdnsDiscovery.hashCode^bindAddr.hashCode^bindPort.hashCode^lanOnly.hashCode^readyTimeoutMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EndpointConfig &&
+                runtimeType == other.runtimeType
+                && relayMode == other.relayMode&& relayUrls == other.relayUrls&& dnsDiscovery == other.dnsDiscovery&& pkarrPublishing == other.pkarrPublishing&& mdnsDiscovery == other.mdnsDiscovery&& bindAddr == other.bindAddr&& bindPort == other.bindPort&& lanOnly == other.lanOnly&& readyTimeoutMs == other.readyTimeoutMs;
+        
+            }
+
+enum MessageLocale {
+                    zh,
+en,
+                    ;
+                    
+                }
+
+class NodeOptions  {
+                final String? storeDir;
+final EndpointConfig endpoint;
+
+                const NodeOptions({this.storeDir ,required this.endpoint ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => storeDir.hashCode^endpoint.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NodeOptions &&
+                runtimeType == other.runtimeType
+                && storeDir == other.storeDir&& endpoint == other.endpoint;
+        
+            }
+
+class PartialDownload  {
+                final String hash;
+final String? ticket;
+final BigInt localBytes;
+final String path;
+
+                const PartialDownload({required this.hash ,this.ticket ,required this.localBytes ,required this.path ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hash.hashCode^ticket.hashCode^localBytes.hashCode^path.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PartialDownload &&
+                runtimeType == other.runtimeType
+                && hash == other.hash&& ticket == other.ticket&& localBytes == other.localBytes&& path == other.path;
+        
+            }
+
+class ProgressInfo  {
+                final BigInt transferId;
+final ProgressOperation operation;
+final ProgressStage stage;
+final BigInt current;
+final BigInt total;
+final BigInt fileCount;
+final BigInt filesDone;
+final String? fileName;
+final BigInt fileCurrent;
+final BigInt fileTotal;
+final BigInt bytesPerSecond;
+final BigInt? etaSecs;
+final BigInt elapsedMs;
+final ConnectionInfo? connection;
+final String message;
+
+                const ProgressInfo({required this.transferId ,required this.operation ,required this.stage ,required this.current ,required this.total ,required this.fileCount ,required this.filesDone ,this.fileName ,required this.fileCurrent ,required this.fileTotal ,required this.bytesPerSecond ,this.etaSecs ,required this.elapsedMs ,this.connection ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => transferId.hashCode^operation.hashCode^stage.hashCode^current.hashCode^total.hashCode^fileCount.hashCode^filesDone.hashCode^fileName.hashCode^fileCurrent.hashCode^fileTotal.hashCode^bytesPerSecond.hashCode^etaSecs.hashCode^elapsedMs.hashCode^connection.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProgressInfo &&
+                runtimeType == other.runtimeType
+                && transferId == other.transferId&& operation == other.operation&& stage == other.stage&& current == other.current&& total == other.total&& fileCount == other.fileCount&& filesDone == other.filesDone&& fileName == other.fileName&& fileCurrent == other.fileCurrent&& fileTotal == other.fileTotal&& bytesPerSecond == other.bytesPerSecond&& etaSecs == other.etaSecs&& elapsedMs == other.elapsedMs&& connection == other.connection&& message == other.message;
+        
+            }
+
+enum ProgressOperation {
+                    import_,
+export_,
+download,
+connect,
+                    ;
+                    
+                }
+
+enum ProgressStage {
+                    importStarted,
+importingFile,
+fileImported,
+importFinished,
+waitingForReceiver,
+parsingTicket,
+connecting,
+fetchingInfo,
+connectionChanged,
+downloadStarted,
+downloadResumed,
+downloading,
+downloadFinished,
+exportingFile,
+exportFinished,
+receiveFinished,
+                    ;
+                    
+                }
+
+enum QrEcLevel {
+                    l,
+m,
+q,
+h,
+                    ;
+                    
+                }
+
+enum QrFormat {
+                    png,
+svg,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class QrImage with _$QrImage  {
+                    const QrImage._();
+
+                     const factory QrImage.png({   required Uint8List bytes , }) = QrImage_Png;
+ const factory QrImage.svg({   required String markup , }) = QrImage_Svg;
+
+                    
+
+                    
+                }
+
+class QrOptions  {
+                final QrFormat format;
+final QrEcLevel ecLevel;
+final int moduleSize;
+
+                const QrOptions({required this.format ,required this.ecLevel ,required this.moduleSize ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => format.hashCode^ecLevel.hashCode^moduleSize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QrOptions &&
+                runtimeType == other.runtimeType
+                && format == other.format&& ecLevel == other.ecLevel&& moduleSize == other.moduleSize;
+        
+            }
+
+class ReceiveOptions  {
+                final String? outputDir;
+final String? tempDir;
+final ConflictPolicy conflictPolicy;
+final String? rawFileName;
+
+                const ReceiveOptions({this.outputDir ,this.tempDir ,required this.conflictPolicy ,this.rawFileName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => outputDir.hashCode^tempDir.hashCode^conflictPolicy.hashCode^rawFileName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReceiveOptions &&
+                runtimeType == other.runtimeType
+                && outputDir == other.outputDir&& tempDir == other.tempDir&& conflictPolicy == other.conflictPolicy&& rawFileName == other.rawFileName;
+        
+            }
+
+class ReceiveResult  {
+                final BigInt transferId;
+final BigInt fileCount;
+final BigInt size;
+final BigInt durationMs;
+final BigInt averageBytesPerSecond;
+final BigInt peakBytesPerSecond;
+final ConnectionInfo? connection;
+
+                const ReceiveResult({required this.transferId ,required this.fileCount ,required this.size ,required this.durationMs ,required this.averageBytesPerSecond ,required this.peakBytesPerSecond ,this.connection ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => transferId.hashCode^fileCount.hashCode^size.hashCode^durationMs.hashCode^averageBytesPerSecond.hashCode^peakBytesPerSecond.hashCode^connection.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReceiveResult &&
+                runtimeType == other.runtimeType
+                && transferId == other.transferId&& fileCount == other.fileCount&& size == other.size&& durationMs == other.durationMs&& averageBytesPerSecond == other.averageBytesPerSecond&& peakBytesPerSecond == other.peakBytesPerSecond&& connection == other.connection;
+        
+            }
+
+enum RelayModeOption {
+                    default_,
+custom,
+disabled,
+                    ;
+                    
+                }
+
+class SendEvent  {
+                final BigInt transferId;
+final SendEventKind kind;
+final BigInt connectionId;
+final BigInt? requestId;
+final String? nodeId;
+final BigInt bytesSent;
+final BigInt total;
+final BigInt bytesPerSecond;
+final BigInt? etaSecs;
+final BigInt elapsedMs;
+
+                const SendEvent({required this.transferId ,required this.kind ,required this.connectionId ,this.requestId ,this.nodeId ,required this.bytesSent ,required this.total ,required this.bytesPerSecond ,this.etaSecs ,required this.elapsedMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => transferId.hashCode^kind.hashCode^connectionId.hashCode^requestId.hashCode^nodeId.hashCode^bytesSent.hashCode^total.hashCode^bytesPerSecond.hashCode^etaSecs.hashCode^elapsedMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SendEvent &&
+                runtimeType == other.runtimeType
+                && transferId == other.transferId&& kind == other.kind&& connectionId == other.connectionId&& requestId == other.requestId&& nodeId == other.nodeId&& bytesSent == other.bytesSent&& total == other.total&& bytesPerSecond == other.bytesPerSecond&& etaSecs == other.etaSecs&& elapsedMs == other.elapsedMs;
+        
+            }
+
+enum SendEventKind {
+                    clientConnected,
+requestReceived,
+progress,
+completed,
+aborted,
+clientDisconnected,
+                    ;
+                    
+                }
+
+class SendOptions  {
+                final bool raw;
+final QrOptions? qr;
+
+                const SendOptions({required this.raw ,this.qr ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => raw.hashCode^qr.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SendOptions &&
+                runtimeType == other.runtimeType
+                && raw == other.raw&& qr == other.qr;
+        
+            }
+
+class SendResult  {
+                final BigInt transferId;
+final String ticket;
+final String hash;
+final BigInt size;
+final BigInt fileCount;
+final TicketWarning? warning;
+final QrImage? qr;
+
+                const SendResult({required this.transferId ,required this.ticket ,required this.hash ,required this.size ,required this.fileCount ,this.warning ,this.qr ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => transferId.hashCode^ticket.hashCode^hash.hashCode^size.hashCode^fileCount.hashCode^warning.hashCode^qr.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SendResult &&
+                runtimeType == other.runtimeType
+                && transferId == other.transferId&& ticket == other.ticket&& hash == other.hash&& size == other.size&& fileCount == other.fileCount&& warning == other.warning&& qr == other.qr;
+        
+            }
+
+class StopSharingResult  {
+                final BigInt transferId;
+final BigInt connectedReceivers;
+
+                const StopSharingResult({required this.transferId ,required this.connectedReceivers ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => transferId.hashCode^connectedReceivers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StopSharingResult &&
+                runtimeType == other.runtimeType
+                && transferId == other.transferId&& connectedReceivers == other.connectedReceivers;
+        
+            }
+
+enum TicketFormat {
+                    raw,
+hashSeq,
+                    ;
+                    
+                }
+
+class TicketInfo  {
+                final String nodeId;
+final String? relayUrl;
+final List<String> directAddrs;
+final String hash;
+final TicketFormat format;
+final bool isCollection;
+final String? fileName;
+
+                const TicketInfo({required this.nodeId ,this.relayUrl ,required this.directAddrs ,required this.hash ,required this.format ,required this.isCollection ,this.fileName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nodeId.hashCode^relayUrl.hashCode^directAddrs.hashCode^hash.hashCode^format.hashCode^isCollection.hashCode^fileName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TicketInfo &&
+                runtimeType == other.runtimeType
+                && nodeId == other.nodeId&& relayUrl == other.relayUrl&& directAddrs == other.directAddrs&& hash == other.hash&& format == other.format&& isCollection == other.isCollection&& fileName == other.fileName;
+        
+            }
+
+enum TicketWarning {
+                    directOnly,
+relayOnly,
+noAddresses,
+                    ;
+                    
+                }
+
+class TransferFile  {
+                final String name;
+final BigInt size;
+
+                const TransferFile({required this.name ,required this.size ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TransferFile &&
+                runtimeType == other.runtimeType
+                && name == other.name&& size == other.size;
+        
+            }
+
+class TransferInfo  {
+                final BigInt id;
+final TransferKind kind;
+final TransferState state;
+final String? ticket;
+final String? error;
+final List<TransferFile> files;
+final String? shortCode;
+
+                const TransferInfo({required this.id ,required this.kind ,required this.state ,this.ticket ,this.error ,required this.files ,this.shortCode ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^kind.hashCode^state.hashCode^ticket.hashCode^error.hashCode^files.hashCode^shortCode.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TransferInfo &&
+                runtimeType == other.runtimeType
+                && id == other.id&& kind == other.kind&& state == other.state&& ticket == other.ticket&& error == other.error&& files == other.files&& shortCode == other.shortCode;
+        
+            }
+
+enum TransferKind {
+                    send,
+receive,
+                    ;
+                    
+                }
+
+enum TransferState {
+                    importing,
+waiting,
+connected,
+transferring,
+exporting,
+done,
+failed,
+cancelled,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,676 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'lib.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$CodeResolverConfig {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() inProcess,
+    required TResult Function(String baseUrl) http,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? inProcess,
+    TResult? Function(String baseUrl)? http,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? inProcess,
+    TResult Function(String baseUrl)? http,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CodeResolverConfig_InProcess value) inProcess,
+    required TResult Function(CodeResolverConfig_Http value) http,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CodeResolverConfig_InProcess value)? inProcess,
+    TResult? Function(CodeResolverConfig_Http value)? http,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CodeResolverConfig_InProcess value)? inProcess,
+    TResult Function(CodeResolverConfig_Http value)? http,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $CodeResolverConfigCopyWith<$Res> {
+  factory $CodeResolverConfigCopyWith(
+          CodeResolverConfig value, $Res Function(CodeResolverConfig) then) =
+      _$CodeResolverConfigCopyWithImpl<$Res, CodeResolverConfig>;
+}
+
+/// @nodoc
+class _$CodeResolverConfigCopyWithImpl<$Res, $Val extends CodeResolverConfig>
+    implements $CodeResolverConfigCopyWith<$Res> {
+  _$CodeResolverConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of CodeResolverConfig
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$CodeResolverConfig_InProcessImplCopyWith<$Res> {
+  factory _$$CodeResolverConfig_InProcessImplCopyWith(
+          _$CodeResolverConfig_InProcessImpl value, $Res Function(_$CodeResolverConfig_InProcessImpl) then) =
+      __$$CodeResolverConfig_InProcessImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CodeResolverConfig_InProcessImplCopyWithImpl<$Res>
+    extends _$CodeResolverConfigCopyWithImpl<$Res, _$CodeResolverConfig_InProcessImpl>
+    implements _$$CodeResolverConfig_InProcessImplCopyWith<$Res> {
+  __$$CodeResolverConfig_InProcessImplCopyWithImpl(
+      _$CodeResolverConfig_InProcessImpl _value, $Res Function(_$CodeResolverConfig_InProcessImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CodeResolverConfig
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CodeResolverConfig_InProcessImpl extends CodeResolverConfig_InProcess {
+  const _$CodeResolverConfig_InProcessImpl() : super._();
+
+  @override
+  String toString() {
+    return 'CodeResolverConfig.inProcess()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CodeResolverConfig_InProcessImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() inProcess,
+    required TResult Function(String baseUrl) http,
+  }) {
+    return inProcess();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? inProcess,
+    TResult? Function(String baseUrl)? http,
+  }) {
+    return inProcess?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? inProcess,
+    TResult Function(String baseUrl)? http,
+    required TResult orElse(),
+  }) {
+    if (inProcess != null) {
+      return inProcess();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CodeResolverConfig_InProcess value) inProcess,
+    required TResult Function(CodeResolverConfig_Http value) http,
+  }) {
+    return inProcess(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CodeResolverConfig_InProcess value)? inProcess,
+    TResult? Function(CodeResolverConfig_Http value)? http,
+  }) {
+    return inProcess?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CodeResolverConfig_InProcess value)? inProcess,
+    TResult Function(CodeResolverConfig_Http value)? http,
+    required TResult orElse(),
+  }) {
+    if (inProcess != null) {
+      return inProcess(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CodeResolverConfig_InProcess extends CodeResolverConfig {
+  const factory CodeResolverConfig_InProcess() =
+      _$CodeResolverConfig_InProcessImpl;
+  const CodeResolverConfig_InProcess._() : super._();
+
+}
+
+/// @nodoc
+abstract class _$$CodeResolverConfig_HttpImplCopyWith<$Res> {
+  factory _$$CodeResolverConfig_HttpImplCopyWith(
+          _$CodeResolverConfig_HttpImpl value, $Res Function(_$CodeResolverConfig_HttpImpl) then) =
+      __$$CodeResolverConfig_HttpImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String baseUrl});
+}
+
+/// @nodoc
+class __$$CodeResolverConfig_HttpImplCopyWithImpl<$Res>
+    extends _$CodeResolverConfigCopyWithImpl<$Res, _$CodeResolverConfig_HttpImpl>
+    implements _$$CodeResolverConfig_HttpImplCopyWith<$Res> {
+  __$$CodeResolverConfig_HttpImplCopyWithImpl(
+      _$CodeResolverConfig_HttpImpl _value, $Res Function(_$CodeResolverConfig_HttpImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of CodeResolverConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? baseUrl = null,
+  }) {
+    return _then(_$CodeResolverConfig_HttpImpl(
+      baseUrl: null == baseUrl
+          ? _value.baseUrl
+          : baseUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CodeResolverConfig_HttpImpl extends CodeResolverConfig_Http {
+  const _$CodeResolverConfig_HttpImpl({required this.baseUrl}) : super._();
+
+  @override
+  final String baseUrl;
+
+  @override
+  String toString() {
+    return 'CodeResolverConfig.http(baseUrl: $baseUrl)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CodeResolverConfig_HttpImpl &&
+            (identical(other.baseUrl, baseUrl) || other.baseUrl == baseUrl));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, baseUrl);
+
+  /// Create a copy of CodeResolverConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CodeResolverConfig_HttpImplCopyWith<_$CodeResolverConfig_HttpImpl> get copyWith =>
+      __$$CodeResolverConfig_HttpImplCopyWithImpl<_$CodeResolverConfig_HttpImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() inProcess,
+    required TResult Function(String baseUrl) http,
+  }) {
+    return http(baseUrl);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? inProcess,
+    TResult? Function(String baseUrl)? http,
+  }) {
+    return http?.call(baseUrl);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? inProcess,
+    TResult Function(String baseUrl)? http,
+    required TResult orElse(),
+  }) {
+    if (http != null) {
+      return http(baseUrl);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CodeResolverConfig_InProcess value) inProcess,
+    required TResult Function(CodeResolverConfig_Http value) http,
+  }) {
+    return http(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CodeResolverConfig_InProcess value)? inProcess,
+    TResult? Function(CodeResolverConfig_Http value)? http,
+  }) {
+    return http?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CodeResolverConfig_InProcess value)? inProcess,
+    TResult Function(CodeResolverConfig_Http value)? http,
+    required TResult orElse(),
+  }) {
+    if (http != null) {
+      return http(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CodeResolverConfig_Http extends CodeResolverConfig {
+  const factory CodeResolverConfig_Http({required final String baseUrl}) =
+      _$CodeResolverConfig_HttpImpl;
+  const CodeResolverConfig_Http._() : super._();
+
+  String get baseUrl;
+
+  /// Create a copy of CodeResolverConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CodeResolverConfig_HttpImplCopyWith<_$CodeResolverConfig_HttpImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$QrImage {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint8List bytes) png,
+    required TResult Function(String markup) svg,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint8List bytes)? png,
+    TResult? Function(String markup)? svg,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint8List bytes)? png,
+    TResult Function(String markup)? svg,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(QrImage_Png value) png,
+    required TResult Function(QrImage_Svg value) svg,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(QrImage_Png value)? png,
+    TResult? Function(QrImage_Svg value)? svg,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(QrImage_Png value)? png,
+    TResult Function(QrImage_Svg value)? svg,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $QrImageCopyWith<$Res> {
+  factory $QrImageCopyWith(
+          QrImage value, $Res Function(QrImage) then) =
+      _$QrImageCopyWithImpl<$Res, QrImage>;
+}
+
+/// @nodoc
+class _$QrImageCopyWithImpl<$Res, $Val extends QrImage>
+    implements $QrImageCopyWith<$Res> {
+  _$QrImageCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of QrImage
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$QrImage_PngImplCopyWith<$Res> {
+  factory _$$QrImage_PngImplCopyWith(
+          _$QrImage_PngImpl value, $Res Function(_$QrImage_PngImpl) then) =
+      __$$QrImage_PngImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List bytes});
+}
+
+/// @nodoc
+class __$$QrImage_PngImplCopyWithImpl<$Res>
+    extends _$QrImageCopyWithImpl<$Res, _$QrImage_PngImpl>
+    implements _$$QrImage_PngImplCopyWith<$Res> {
+  __$$QrImage_PngImplCopyWithImpl(
+      _$QrImage_PngImpl _value, $Res Function(_$QrImage_PngImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of QrImage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? bytes = null,
+  }) {
+    return _then(_$QrImage_PngImpl(
+      bytes: null == bytes
+          ? _value.bytes
+          : bytes // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$QrImage_PngImpl extends QrImage_Png {
+  const _$QrImage_PngImpl({required this.bytes}) : super._();
+
+  @override
+  final Uint8List bytes;
+
+  @override
+  String toString() {
+    return 'QrImage.png(bytes: $bytes)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$QrImage_PngImpl &&
+            const DeepCollectionEquality().equals(other.bytes, bytes));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(bytes));
+
+  /// Create a copy of QrImage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$QrImage_PngImplCopyWith<_$QrImage_PngImpl> get copyWith =>
+      __$$QrImage_PngImplCopyWithImpl<_$QrImage_PngImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint8List bytes) png,
+    required TResult Function(String markup) svg,
+  }) {
+    return png(bytes);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint8List bytes)? png,
+    TResult? Function(String markup)? svg,
+  }) {
+    return png?.call(bytes);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint8List bytes)? png,
+    TResult Function(String markup)? svg,
+    required TResult orElse(),
+  }) {
+    if (png != null) {
+      return png(bytes);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(QrImage_Png value) png,
+    required TResult Function(QrImage_Svg value) svg,
+  }) {
+    return png(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(QrImage_Png value)? png,
+    TResult? Function(QrImage_Svg value)? svg,
+  }) {
+    return png?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(QrImage_Png value)? png,
+    TResult Function(QrImage_Svg value)? svg,
+    required TResult orElse(),
+  }) {
+    if (png != null) {
+      return png(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class QrImage_Png extends QrImage {
+  const factory QrImage_Png({required final Uint8List bytes}) =
+      _$QrImage_PngImpl;
+  const QrImage_Png._() : super._();
+
+  Uint8List get bytes;
+
+  /// Create a copy of QrImage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$QrImage_PngImplCopyWith<_$QrImage_PngImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$QrImage_SvgImplCopyWith<$Res> {
+  factory _$$QrImage_SvgImplCopyWith(
+          _$QrImage_SvgImpl value, $Res Function(_$QrImage_SvgImpl) then) =
+      __$$QrImage_SvgImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String markup});
+}
+
+/// @nodoc
+class __$$QrImage_SvgImplCopyWithImpl<$Res>
+    extends _$QrImageCopyWithImpl<$Res, _$QrImage_SvgImpl>
+    implements _$$QrImage_SvgImplCopyWith<$Res> {
+  __$$QrImage_SvgImplCopyWithImpl(
+      _$QrImage_SvgImpl _value, $Res Function(_$QrImage_SvgImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of QrImage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? markup = null,
+  }) {
+    return _then(_$QrImage_SvgImpl(
+      markup: null == markup
+          ? _value.markup
+          : markup // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$QrImage_SvgImpl extends QrImage_Svg {
+  const _$QrImage_SvgImpl({required this.markup}) : super._();
+
+  @override
+  final String markup;
+
+  @override
+  String toString() {
+    return 'QrImage.svg(markup: $markup)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$QrImage_SvgImpl &&
+            (identical(other.markup, markup) || other.markup == markup));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, markup);
+
+  /// Create a copy of QrImage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$QrImage_SvgImplCopyWith<_$QrImage_SvgImpl> get copyWith =>
+      __$$QrImage_SvgImplCopyWithImpl<_$QrImage_SvgImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint8List bytes) png,
+    required TResult Function(String markup) svg,
+  }) {
+    return svg(markup);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint8List bytes)? png,
+    TResult? Function(String markup)? svg,
+  }) {
+    return svg?.call(markup);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint8List bytes)? png,
+    TResult Function(String markup)? svg,
+    required TResult orElse(),
+  }) {
+    if (svg != null) {
+      return svg(markup);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(QrImage_Png value) png,
+    required TResult Function(QrImage_Svg value) svg,
+  }) {
+    return svg(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(QrImage_Png value)? png,
+    TResult? Function(QrImage_Svg value)? svg,
+  }) {
+    return svg?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(QrImage_Png value)? png,
+    TResult Function(QrImage_Svg value)? svg,
+    required TResult orElse(),
+  }) {
+    if (svg != null) {
+      return svg(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class QrImage_Svg extends QrImage {
+  const factory QrImage_Svg({required final String markup}) =
+      _$QrImage_SvgImpl;
+  const QrImage_Svg._() : super._();
+
+  String get markup;
+
+  /// Create a copy of QrImage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$QrImage_SvgImplCopyWith<_$QrImage_SvgImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
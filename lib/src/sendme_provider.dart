@@ -1,6 +1,10 @@
 import 'package:flutter/foundation.dart';
 import 'dart:async';
+import 'package:path_provider/path_provider.dart';
+import 'rust/api/identity.dart';
+import 'rust/api/node.dart';
 import 'rust/api/sendme.dart';
+import 'rust/error.dart';
 import 'rust/lib.dart';
 
 class SendmeProvider extends ChangeNotifier {
@@ -15,11 +19,64 @@ class SendmeProvider extends ChangeNotifier {
   double _receiveProgress = 0.0;
   String _sendProgressMessage = '准备发送...';
   String _receiveProgressMessage = '准备接收...';
-  Timer? _progressTimer;
+  StreamSubscription<ProgressInfo>? _progressSubscription;
+  StreamSubscription<SendEvent>? _sendEventSubscription;
 
   // Real progress tracking
   String? _sendTicket;
   String? _receiveTicket;
+  BigInt? _receiveTransferId;
+  ConnectionInfo? _connection;
+  String? _nodeId;
+
+  // Relays, discovery and bind address of the node
+  EndpointConfig _endpointConfig = defaultEndpointConfig();
+  late final Future<void> _nodeStarted;
+
+  SendmeProvider() {
+    _progressSubscription = progressStream().listen(_onProgress);
+    _sendEventSubscription = sendEventStream().listen(_onSendEvent);
+    _nodeStarted = _start();
+  }
+
+  // Loads the identity first so the node keeps the same node ID across
+  // restarts
+  Future<void> _start() async {
+    try {
+      final configDir = await getApplicationSupportDirectory();
+      _nodeId = await initIdentity(configDir: configDir.path);
+    } catch (e) {
+      _nodeId = nodeId();
+      _error = _describeError(e);
+    }
+    await _startNode();
+  }
+
+  Future<void> _startNode() async {
+    try {
+      final storeDir = await getTemporaryDirectory();
+      _nodeId = await initNode(
+        options: NodeOptions(
+          storeDir: storeDir.path,
+          endpoint: _endpointConfig,
+        ),
+      );
+    } catch (e) {
+      _error = _describeError(e);
+    }
+    notifyListeners();
+  }
+
+  EndpointConfig get endpointConfig => _endpointConfig;
+
+  // Restarts the node with the new configuration, which stops all shares
+  Future<void> setEndpointConfig(EndpointConfig config) async {
+    await _nodeStarted;
+    _endpointConfig = config;
+    _isSending = false;
+    _sendResult = null;
+    await _startNode();
+  }
 
   // Getters
   bool get isSending => _isSending;
@@ -31,8 +88,13 @@ class SendmeProvider extends ChangeNotifier {
   double get receiveProgress => _receiveProgress;
   String get sendProgressMessage => _sendProgressMessage;
   String get receiveProgressMessage => _receiveProgressMessage;
+  ConnectionInfo? get connection => _connection;
+  String? get nodeId => _nodeId;
+
+  Future<void> sendFileToPeer(String path) => sendFilesToPeer([path]);
 
-  Future<void> sendFileToPeer(String path) async {
+  // Files picked from different folders go out as one collection
+  Future<void> sendFilesToPeer(List<String> paths) async {
     try {
       _clearResults();
       _isSending = true;
@@ -41,27 +103,28 @@ class SendmeProvider extends ChangeNotifier {
       _sendProgressMessage = '正在导入文件...';
       notifyListeners();
 
-      // Start real progress tracking
-      _startRealSendProgress();
-
-      final result = await sendFile(path: path);
+      await _nodeStarted;
+      final result = await sendFiles(
+        paths: paths,
+        options: SendOptions(raw: false, qr: defaultQrOptions()),
+      );
 
       // Store ticket for progress tracking
       _sendTicket = result.ticket;
 
       // Complete initial preparation phase
-      _progressTimer?.cancel();
       _sendProgress = 0.8;
-      _sendProgressMessage = '文件准备完成，等待接收方连接...';
+      _sendProgressMessage = switch (result.warning) {
+        null => '文件准备完成，等待接收方连接...',
+        TicketWarning.directOnly => '未连接中继服务器，只有能直连本机的设备可以接收',
+        TicketWarning.relayOnly => '没有可直连的地址，传输将通过中继服务器',
+        TicketWarning.noAddresses => '网络不可用，接收方可能无法连接',
+      };
       _sendResult = result;
 
-      // Start monitoring for connection/transfer progress
-      _startSendTransferMonitoring();
-
       notifyListeners();
     } catch (e) {
-      _progressTimer?.cancel();
-      _error = e.toString();
+      _error = _describeError(e);
       _isSending = false;
       _sendProgress = 0.0;
       notifyListeners();
@@ -78,27 +141,84 @@ class SendmeProvider extends ChangeNotifier {
       _receiveTicket = ticket;
       notifyListeners();
 
-      // Start real receive progress tracking
-      _startRealReceiveProgress();
-
-      final result = await receiveFile(ticket: ticket);
+      await _nodeStarted;
+      // Known before the download starts, so it can be cancelled and its
+      // progress told apart from other receives
+      final transferId = createReceive();
+      _receiveTransferId = transferId;
+      final result = await receiveFile(
+        transferId: transferId,
+        ticket: ticket,
+        options: await _receiveOptions(),
+      );
 
       // Complete progress
-      _progressTimer?.cancel();
       _receiveProgress = 1.0;
       _receiveProgressMessage = '接收完成！';
       _receiveResult = result;
       _isReceiving = false;
       notifyListeners();
     } catch (e) {
-      _progressTimer?.cancel();
-      _error = e.toString();
+      _error = _describeError(e);
       _isReceiving = false;
       _receiveProgress = 0.0;
       notifyListeners();
     }
   }
 
+  // Stops the running receive. With keepPartial the downloaded part stays
+  // around and the receive can be resumed with the same ticket.
+  Future<void> cancelReceive({bool keepPartial = false}) async {
+    final transferId = _receiveTransferId;
+    if (!_isReceiving || transferId == null) return;
+    try {
+      await cancelTransfer(id: transferId, keepPartial: keepPartial);
+    } catch (e) {
+      _error = _describeError(e);
+      notifyListeners();
+    }
+  }
+
+  // The working directory is not writable on mobile platforms, so files go
+  // to the downloads folder and partial downloads live in app support storage
+  // where they survive restarts and can be resumed.
+  Future<ReceiveOptions> _receiveOptions() async {
+    final outputDir =
+        await getDownloadsDirectory() ?? await getApplicationDocumentsDirectory();
+    final tempDir = await getApplicationSupportDirectory();
+    return ReceiveOptions(
+      outputDir: outputDir.path,
+      tempDir: tempDir.path,
+      conflictPolicy: ConflictPolicy.fail,
+    );
+  }
+
+  String _describeError(Object error) {
+    if (error is! SendmeError) return error.toString();
+    return switch (error) {
+      SendmeError_InvalidTicket() => '无效的 ticket',
+      SendmeError_UnknownCode(:final code) => '找不到短码 $code 对应的 ticket',
+      SendmeError_InvalidIdentity() => '无效的身份密钥',
+      SendmeError_InvalidConfig(:final reason) => '配置无效：$reason',
+      SendmeError_NodeNotStarted() => '网络节点未启动',
+      SendmeError_ConnectTimeout(:final timeoutSecs) =>
+        '连接发送方超时（${timeoutSecs}秒）',
+      SendmeError_PeerUnreachable() => '无法连接到发送方',
+      SendmeError_TargetExists(:final path) => '文件已存在：$path',
+      SendmeError_Io(:final path, :final message) =>
+        path == null ? '文件读写失败：$message' : '文件读写失败：$path',
+      SendmeError_StoreCorrupt() => '本地缓存已损坏，请删除后重试',
+      SendmeError_Cancelled() => '传输已取消',
+      SendmeError_PathRejected(:final name) => '文件名不安全：$name',
+      SendmeError_UnknownTransfer() => '找不到该传输',
+      SendmeError_DownloadFailed() => '下载中断，与发送方的连接出错',
+      SendmeError_TransferFinished() => '传输已经结束',
+      SendmeError_TransferRunning() => '传输仍在进行中',
+      SendmeError_NoFreeShortCode() => '暂时无法生成短码，请稍后重试',
+      SendmeError_Other(:final message) => message,
+    };
+  }
+
   void clearError() {
     _error = null;
     notifyListeners();
@@ -114,65 +234,82 @@ class SendmeProvider extends ChangeNotifier {
     _receiveProgressMessage = '准备接收...';
     _sendTicket = null;
     _receiveTicket = null;
-    _progressTimer?.cancel();
+    _receiveTransferId = null;
+    _connection = null;
   }
 
-  void _startRealSendProgress() {
-    _sendProgress = 0.1;
-    _sendProgressMessage = '正在导入文件...';
-
-    _progressTimer = Timer.periodic(const Duration(milliseconds: 500), (timer) {
-      if (_sendProgress < 0.7) {
-        _sendProgress += 0.1;
-        if (_sendProgress < 0.3) {
-          _sendProgressMessage = '正在导入文件...';
-        } else if (_sendProgress < 0.5) {
-          _sendProgressMessage = '正在生成哈希值...';
-        } else {
-          _sendProgressMessage = '正在创建网络连接...';
-        }
-        notifyListeners();
-      } else {
-        timer.cancel();
-      }
-    });
-  }
+  // Uploads take the last part of the send progress bar
+  void _onSendEvent(SendEvent event) {
+    if (_sendResult?.transferId != event.transferId) return;
+    final node = event.nodeId == null
+        ? '接收方'
+        : '接收方 ${event.nodeId!.substring(0, 8)}';
 
-  void _startSendTransferMonitoring() {
-    // Monitor for transfer completion
-    _progressTimer = Timer.periodic(const Duration(seconds: 1), (timer) {
-      // For now, we don't have a direct way to monitor transfer progress from Dart
-      // In a real implementation, we would poll Rust for progress updates
-      // The current sendme implementation keeps the sender alive until manually stopped
-      if (_sendProgress >= 0.8) {
-        _sendProgress = 0.9;
-        _sendProgressMessage = '等待接收方连接并传输数据...';
-        notifyListeners();
-      }
-    });
+    switch (event.kind) {
+      case SendEventKind.clientConnected:
+        _sendProgressMessage = '$node 已连接';
+      case SendEventKind.requestReceived:
+        _sendProgressMessage = '$node 开始下载...';
+      case SendEventKind.progress:
+        final total = event.total.toDouble();
+        final fraction = total > 0
+            ? (event.bytesSent.toDouble() / total).clamp(0.0, 1.0)
+            : 0.0;
+        _sendProgress = 0.8 + fraction * 0.2;
+        _sendProgressMessage =
+            '正在发送给 $node... ${(fraction * 100).toStringAsFixed(1)}%，'
+            '${formatBytes(size: event.bytesPerSecond)}/s';
+      case SendEventKind.completed:
+        _sendProgress = 1.0;
+        _sendProgressMessage = '$node 已接收完成';
+      case SendEventKind.aborted:
+        _sendProgress = 0.8;
+        _sendProgressMessage = '$node 中止了下载';
+      case SendEventKind.clientDisconnected:
+        _sendProgressMessage = '$node 已断开连接';
+    }
+    notifyListeners();
   }
 
-  void _startRealReceiveProgress() {
-    _receiveProgress = 0.1;
-    _receiveProgressMessage = '正在解析 ticket...';
-
-    _progressTimer = Timer.periodic(const Duration(seconds: 2), (timer) {
-      // The real progress will be updated by the Rust backend
-      // This is just to show activity during potentially long operations
-      if (_receiveProgress < 0.3) {
-        _receiveProgressMessage = '正在连接到发送方...';
-        _receiveProgress = 0.2;
-      } else if (_receiveProgress < 0.6) {
-        _receiveProgressMessage = '正在获取文件信息...';
-        _receiveProgress = 0.4;
-      } else if (_receiveProgress < 0.9) {
-        _receiveProgressMessage = '正在下载和导出文件...';
-        _receiveProgress = 0.7;
-      } else {
-        _receiveProgress = 0.85;
-        _receiveProgressMessage = '正在完成最后步骤...';
-      }
+  void _onProgress(ProgressInfo info) {
+    final isOwnReceive = _isReceiving && info.transferId == _receiveTransferId;
+    if (info.operation != ProgressOperation.import_ && !isOwnReceive) return;
+    if (info.connection != null) {
+      _connection = info.connection;
+    }
+    // Path changes can happen at any time and do not move the progress bar
+    if (info.stage == ProgressStage.connectionChanged) {
       notifyListeners();
-    });
+      return;
+    }
+
+    final total = info.total.toDouble();
+    final fraction =
+        total > 0 ? (info.current.toDouble() / total).clamp(0.0, 1.0) : 0.0;
+
+    switch (info.operation) {
+      case ProgressOperation.import_:
+        if (!_isSending) return;
+        // Importing is the preparation phase, the rest is waiting for peers
+        _sendProgress = fraction * 0.8;
+        _sendProgressMessage = info.message;
+      case ProgressOperation.connect:
+        _receiveProgress = fraction * 0.1;
+        _receiveProgressMessage = info.message;
+      case ProgressOperation.download:
+        _receiveProgress = 0.1 + fraction * 0.8;
+        _receiveProgressMessage = info.message;
+      case ProgressOperation.export_:
+        _receiveProgress = 0.9 + fraction * 0.1;
+        _receiveProgressMessage = info.message;
+    }
+    notifyListeners();
+  }
+
+  @override
+  void dispose() {
+    _progressSubscription?.cancel();
+    _sendEventSubscription?.cancel();
+    super.dispose();
   }
 }
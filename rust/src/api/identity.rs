@@ -0,0 +1,41 @@
+use crate::{
+    error::SendmeError,
+    identity::{
+        export_identity as core_export_identity, import_identity as core_import_identity,
+        init_identity as core_init_identity, node_id as core_node_id,
+        rotate_identity as core_rotate_identity,
+    },
+};
+use flutter_rust_bridge::frb;
+
+// Load the node identity from an app private directory, creating it on first
+// use. Call this before `init_node` to keep the same node ID across
+// restarts. Returns the node ID.
+#[frb]
+pub async fn init_identity(config_dir: String) -> Result<String, SendmeError> {
+    Ok(core_init_identity(config_dir).await?)
+}
+
+// The public node ID other devices see
+#[frb(sync)]
+pub fn node_id() -> String {
+    core_node_id()
+}
+
+// Switch to a new identity and return its node ID
+#[frb]
+pub async fn rotate_identity() -> Result<String, SendmeError> {
+    Ok(core_rotate_identity().await?)
+}
+
+// The secret key for backups. Keep it private, it lets anyone act as this node.
+#[frb(sync)]
+pub fn export_identity() -> String {
+    core_export_identity()
+}
+
+// Restore an identity from `export_identity` and return its node ID
+#[frb]
+pub async fn import_identity(secret: String) -> Result<String, SendmeError> {
+    Ok(core_import_identity(secret).await?)
+}
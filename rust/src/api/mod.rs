@@ -1,2 +1,5 @@
 pub mod simple;
 pub mod sendme;
+pub mod identity;
+pub mod node;
+pub mod short_code;
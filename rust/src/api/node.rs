@@ -0,0 +1,21 @@
+use crate::{
+    error::SendmeError,
+    node::{init_node as core_init_node, shutdown_node as core_shutdown_node},
+    NodeOptions,
+};
+use flutter_rust_bridge::frb;
+
+// Start the endpoint all sends and receives go through and return its node
+// ID. Call `init_identity` first so the node ID stays the same across
+// restarts. Calling it again restarts the node with the new options and stops
+// all shares.
+#[frb]
+pub async fn init_node(options: NodeOptions) -> Result<String, SendmeError> {
+    Ok(core_init_node(options).await?)
+}
+
+// Stop all shares and close the endpoint
+#[frb]
+pub async fn shutdown_node() -> Result<(), SendmeError> {
+    Ok(core_shutdown_node().await?)
+}
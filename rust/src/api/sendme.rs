@@ -1,9 +1,18 @@
 use crate::{
+    error::SendmeError,
+    frb_generated::StreamSink,
+    qr::qr_code as core_qr_code,
     sendme_core::{
-        format_bytes as core_format_bytes, receive_file as core_receive_file,
-        send_file as core_send_file,
+        cancel_transfer as core_cancel_transfer, create_receive as core_create_receive,
+        delete_partial_download as core_delete_partial_download,
+        forget_transfer as core_forget_transfer, format_bytes as core_format_bytes,
+        list_partial_downloads as core_list_partial_downloads,
+        parse_ticket as core_parse_ticket,
+        receive_file as core_receive_file, send_file as core_send_file,
+        send_files as core_send_files,
+        stop_sharing as core_stop_sharing,
     },
-    ProgressInfo, ProgressSender, ReceiveResult, SendResult,
+    EndpointConfig, MessageLocale, PartialDownload, ProgressInfo, ReceiveOptions, ReceiveResult, QrImage, QrOptions, SendEvent, SendOptions, SendResult, StopSharingResult, TicketInfo, TransferId, TransferInfo, SENDME_STATE,
 };
 use flutter_rust_bridge::frb;
 
@@ -13,16 +22,136 @@ pub fn init_logging() {
     crate::sendme_core::init_logging();
 }
 
+// Subscribe to progress updates of every send and receive, including ones
+// that started before. Subscribing again replaces (and closes) the previous
+// stream; running transfers continue on the new one.
+#[frb(sync)]
+pub fn progress_stream(sink: StreamSink<ProgressInfo>) {
+    SENDME_STATE.set_progress_sink(sink);
+}
+
+// Subscribe to what receivers do with the collections being shared: who
+// connects, what they request and how far their downloads are
+#[frb(sync)]
+pub fn send_event_stream(sink: StreamSink<SendEvent>) {
+    SENDME_STATE.set_send_event_sink(sink);
+}
+
+// The endpoint configuration used when the app does not set its own
+#[frb(sync)]
+pub fn default_endpoint_config() -> EndpointConfig {
+    EndpointConfig::default()
+}
+
+// Endpoint configuration for transfers without internet access between
+// devices on the same network
+#[frb(sync)]
+pub fn lan_only_endpoint_config() -> EndpointConfig {
+    EndpointConfig::lan_only()
+}
+
+// Medium error correction, 8 pixel modules, PNG
+#[frb(sync)]
+pub fn default_qr_options() -> QrOptions {
+    QrOptions::default()
+}
+
+// Choose the language of `ProgressInfo.message` for future updates
+#[frb(sync)]
+pub fn set_locale(locale: MessageLocale) {
+    SENDME_STATE.set_locale(locale);
+}
+
 // Send a file or directory
 #[frb]
-pub async fn send_file(path: String) -> anyhow::Result<SendResult> {
-    core_send_file(path).await
+pub async fn send_file(path: String, options: SendOptions) -> Result<SendResult, SendmeError> {
+    Ok(core_send_file(path, options).await?)
+}
+
+// Send several files and directories as one collection. Each path becomes a
+// top level entry named after it; on name clashes later paths get a " (1)",
+// " (2)", ... suffix.
+#[frb]
+pub async fn send_files(paths: Vec<String>, options: SendOptions) -> Result<SendResult, SendmeError> {
+    Ok(core_send_files(paths, options).await?)
+}
+
+// Stop sharing a file or directory, by transfer ID or ticket. Receivers that
+// are still downloading are cut off; the data is removed from the node's
+// store with its next garbage collection.
+#[frb]
+pub async fn stop_sharing(ticket_or_id: String) -> Result<StopSharingResult, SendmeError> {
+    Ok(core_stop_sharing(ticket_or_id).await?)
+}
+
+// Register a receive and return its ID. Progress updates and
+// `cancel_transfer` use this ID while `receive_file` runs.
+#[frb(sync)]
+pub fn create_receive() -> TransferId {
+    core_create_receive()
+}
+
+// Receive a file or directory, from a ticket or a short code, as the
+// receive returned by `create_receive`
+#[frb]
+pub async fn receive_file(
+    transfer_id: TransferId,
+    ticket: String,
+    options: ReceiveOptions,
+) -> Result<ReceiveResult, SendmeError> {
+    Ok(core_receive_file(transfer_id, ticket, options).await?)
+}
+
+// Cancel a running send or receive. With `keep_partial` a cancelled receive
+// keeps what it downloaded so far instead of deleting it.
+#[frb]
+pub async fn cancel_transfer(id: TransferId, keep_partial: bool) -> Result<(), SendmeError> {
+    Ok(core_cancel_transfer(id, keep_partial).await?)
 }
 
-// Receive a file or directory
+// List receives that failed or were cancelled and can be resumed by calling
+// `receive_file` with the same ticket and temp directory again
 #[frb]
-pub async fn receive_file(ticket: String) -> anyhow::Result<ReceiveResult> {
-    core_receive_file(ticket).await
+pub async fn list_partial_downloads(temp_dir: Option<String>) -> Result<Vec<PartialDownload>, SendmeError> {
+    Ok(core_list_partial_downloads(temp_dir).await?)
+}
+
+// Discard a partial download by its hash
+#[frb]
+pub async fn delete_partial_download(hash: String, temp_dir: Option<String>) -> Result<(), SendmeError> {
+    Ok(core_delete_partial_download(hash, temp_dir).await?)
+}
+
+// Look up a transfer by ID, including finished ones
+#[frb(sync)]
+pub fn get_transfer(id: TransferId) -> Option<TransferInfo> {
+    SENDME_STATE.transfer(id)
+}
+
+// Drop a finished transfer from the registry. The oldest finished transfers
+// are also dropped on their own once there are many of them.
+#[frb(sync)]
+pub fn forget_transfer(id: TransferId) -> Result<(), SendmeError> {
+    Ok(core_forget_transfer(id)?)
+}
+
+// List all transfers that are neither done nor failed
+#[frb(sync)]
+pub fn list_transfers() -> Vec<TransferInfo> {
+    SENDME_STATE.active_transfers()
+}
+
+// Read the node, addresses and hash out of a ticket without connecting
+#[frb(sync)]
+pub fn parse_ticket(ticket: String) -> Result<TicketInfo, SendmeError> {
+    Ok(core_parse_ticket(ticket)?)
+}
+
+// Render a ticket or short code as a QR code, e.g. when the one from
+// `send_file` is needed in another size
+#[frb(sync)]
+pub fn qr_code(data: String, options: QrOptions) -> Result<QrImage, SendmeError> {
+    Ok(core_qr_code(&data, &options)?)
 }
 
 // Format bytes for display
@@ -30,4 +159,3 @@ pub async fn receive_file(ticket: String) -> anyhow::Result<ReceiveResult> {
 pub fn format_bytes(size: u64) -> String {
     core_format_bytes(size)
 }
-
@@ -0,0 +1,30 @@
+use crate::{
+    error::SendmeError,
+    short_code::{
+        publish_short_code as core_publish_short_code,
+        resolve_short_code as core_resolve_short_code,
+        set_code_resolver as core_set_code_resolver,
+    },
+    CodeResolverConfig, TransferId,
+};
+use flutter_rust_bridge::frb;
+
+// Choose where short codes are published. Both devices need the same
+// resolver; the default only works within this app instance.
+#[frb(sync)]
+pub fn set_code_resolver(config: CodeResolverConfig) -> Result<(), SendmeError> {
+    Ok(core_set_code_resolver(config)?)
+}
+
+// Publish a short code like "K7QM-4XWD" for a running send. `receive_file`
+// accepts the code in place of the ticket.
+#[frb]
+pub async fn publish_short_code(transfer_id: TransferId) -> Result<String, SendmeError> {
+    Ok(core_publish_short_code(transfer_id).await?)
+}
+
+// Look up the ticket behind a short code
+#[frb]
+pub async fn resolve_short_code(code: String) -> Result<String, SendmeError> {
+    Ok(core_resolve_short_code(code).await?)
+}
@@ -0,0 +1,172 @@
+use std::{fmt, path::PathBuf};
+
+// Longest file name most file systems accept, in bytes
+pub const MAX_COMPONENT_LEN: usize = 255;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PathRejectReason {
+    Empty,
+    Absolute,
+    DrivePrefix,
+    ParentComponent,
+    NulByte,
+    InvalidCharacter,
+    ReservedName,
+    ComponentTooLong,
+}
+
+impl fmt::Display for PathRejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            PathRejectReason::Empty => "empty path component",
+            PathRejectReason::Absolute => "absolute path",
+            PathRejectReason::DrivePrefix => "drive prefix",
+            PathRejectReason::ParentComponent => "parent directory component",
+            PathRejectReason::NulByte => "NUL byte",
+            PathRejectReason::InvalidCharacter => "invalid character",
+            PathRejectReason::ReservedName => "reserved device name",
+            PathRejectReason::ComponentTooLong => "path component too long",
+        };
+        f.write_str(reason)
+    }
+}
+
+// A collection entry name that is not safe to export
+#[derive(Debug, Clone)]
+pub struct PathRejected {
+    pub name: String,
+    pub reason: PathRejectReason,
+}
+
+impl fmt::Display for PathRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "collection entry {:?} rejected: {}", self.name, self.reason)
+    }
+}
+
+impl std::error::Error for PathRejected {}
+
+// Turns a collection entry name chosen by the sender into a relative path
+// that cannot leave the export directory on any platform. Both `/` and `\`
+// are treated as separators so Windows style names are checked as well.
+pub fn safe_relative_path(name: &str) -> Result<PathBuf, PathRejected> {
+    let reject = |reason| {
+        Err(PathRejected {
+            name: name.to_string(),
+            reason,
+        })
+    };
+
+    if name.is_empty() {
+        return reject(PathRejectReason::Empty);
+    }
+    if name.contains('\0') {
+        return reject(PathRejectReason::NulByte);
+    }
+    if name.starts_with(['/', '\\']) {
+        return reject(PathRejectReason::Absolute);
+    }
+
+    let mut path = PathBuf::new();
+    for (i, component) in name.split(['/', '\\']).enumerate() {
+        match component {
+            "" | "." => return reject(PathRejectReason::Empty),
+            ".." => return reject(PathRejectReason::ParentComponent),
+            _ => {}
+        }
+        if i == 0 && has_drive_prefix(component) {
+            return reject(PathRejectReason::DrivePrefix);
+        }
+        if component.len() > MAX_COMPONENT_LEN {
+            return reject(PathRejectReason::ComponentTooLong);
+        }
+        if component
+            .chars()
+            .any(|c| c.is_control() || matches!(c, ':' | '<' | '>' | '"' | '|' | '?' | '*'))
+        {
+            return reject(PathRejectReason::InvalidCharacter);
+        }
+        if is_reserved_device_name(component) {
+            return reject(PathRejectReason::ReservedName);
+        }
+        path.push(component);
+    }
+    Ok(path)
+}
+
+fn has_drive_prefix(component: &str) -> bool {
+    let bytes = component.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+// Windows refuses these names with any extension, e.g. `nul.txt`
+fn is_reserved_device_name(component: &str) -> bool {
+    let stem = component
+        .split('.')
+        .next()
+        .unwrap_or(component)
+        .trim_end_matches(' ')
+        .to_ascii_uppercase();
+    match stem.as_str() {
+        "CON" | "PRN" | "AUX" | "NUL" | "CONIN$" | "CONOUT$" => true,
+        _ => {
+            let bytes = stem.as_bytes();
+            bytes.len() == 4
+                && (stem.starts_with("COM") || stem.starts_with("LPT"))
+                && bytes[3].is_ascii_digit()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unsafe_names() {
+        let long = "a".repeat(MAX_COMPONENT_LEN + 1);
+        let cases = [
+            ("", PathRejectReason::Empty),
+            ("a//b", PathRejectReason::Empty),
+            ("./a", PathRejectReason::Empty),
+            ("../x", PathRejectReason::ParentComponent),
+            ("a/../../x", PathRejectReason::ParentComponent),
+            ("a\\..\\x", PathRejectReason::ParentComponent),
+            ("/etc/passwd", PathRejectReason::Absolute),
+            ("\\\\server\\share", PathRejectReason::Absolute),
+            ("C:foo", PathRejectReason::DrivePrefix),
+            ("c:\\windows", PathRejectReason::DrivePrefix),
+            ("a\0b", PathRejectReason::NulByte),
+            ("a/b:c", PathRejectReason::InvalidCharacter),
+            ("a\nb", PathRejectReason::InvalidCharacter),
+            ("nul.txt", PathRejectReason::ReservedName),
+            ("dir/COM1", PathRejectReason::ReservedName),
+            ("Lpt9.tar.gz", PathRejectReason::ReservedName),
+            (long.as_str(), PathRejectReason::ComponentTooLong),
+        ];
+        for (name, reason) in cases {
+            let rejected = safe_relative_path(name).expect_err(name);
+            assert_eq!(rejected.reason, reason, "{:?}", name);
+            assert_eq!(rejected.name, name);
+        }
+    }
+
+    #[test]
+    fn accepts_nested_names() {
+        let longest = "a".repeat(MAX_COMPONENT_LEN);
+        let cases = [
+            ("file.txt", vec!["file.txt"]),
+            ("a/b/c.txt", vec!["a", "b", "c.txt"]),
+            ("dir\\sub\\file", vec!["dir", "sub", "file"]),
+            ("..hidden/.config", vec!["..hidden", ".config"]),
+            ("console.log", vec!["console.log"]),
+            ("COM10", vec!["COM10"]),
+            ("résumé.pdf", vec!["résumé.pdf"]),
+            (longest.as_str(), vec![longest.as_str()]),
+        ];
+        for (name, components) in cases {
+            let path = safe_relative_path(name).unwrap();
+            assert_eq!(path, components.iter().collect::<PathBuf>(), "{:?}", name);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    entry_names::{PathRejectReason, PathRejected},
+    TransferId, TransferState,
+};
+use std::fmt;
+
+// Errors returned across the FFI boundary. Every variant carries the data the
+// app needs to show a localized message, so no user facing text is built here.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum SendmeError {
+    InvalidTicket { reason: String },
+    UnknownCode { code: String },
+    InvalidIdentity { reason: String },
+    InvalidConfig { reason: String },
+    NodeNotStarted,
+    ConnectTimeout { node_id: String, timeout_secs: u64 },
+    PeerUnreachable { node_id: String, reason: String },
+    TargetExists { path: String },
+    Io { path: Option<String>, message: String },
+    StoreCorrupt { hash: String, message: String },
+    Cancelled { transfer_id: TransferId },
+    PathRejected { name: String, reason: PathRejectReason },
+    UnknownTransfer { id_or_ticket: String },
+    // The connection to the sender failed after the download had started
+    DownloadFailed { node_id: String, reason: String },
+    // Cancelling a transfer that has already finished
+    TransferFinished { transfer_id: TransferId, state: TransferState },
+    // Forgetting or deleting the data of a transfer that is still running
+    TransferRunning { transfer_id: TransferId, state: TransferState },
+    NoFreeShortCode { attempts: u32 },
+    Other { message: String },
+}
+
+impl fmt::Display for SendmeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendmeError::InvalidTicket { reason } => write!(f, "invalid ticket: {}", reason),
+            SendmeError::UnknownCode { code } => write!(f, "no ticket found for code {}", code),
+            SendmeError::InvalidIdentity { reason } => write!(f, "invalid identity: {}", reason),
+            SendmeError::InvalidConfig { reason } => {
+                write!(f, "invalid configuration: {}", reason)
+            }
+            SendmeError::NodeNotStarted => f.write_str("the node has not been started"),
+            SendmeError::ConnectTimeout {
+                node_id,
+                timeout_secs,
+            } => write!(f, "could not connect to {} within {}s", node_id, timeout_secs),
+            SendmeError::PeerUnreachable { node_id, reason } => {
+                write!(f, "could not connect to {}: {}", node_id, reason)
+            }
+            SendmeError::TargetExists { path } => write!(f, "target {} already exists", path),
+            SendmeError::Io {
+                path: Some(path),
+                message,
+            } => write!(f, "{}: {}", path, message),
+            SendmeError::Io {
+                path: None,
+                message,
+            } => f.write_str(message),
+            SendmeError::StoreCorrupt { hash, message } => {
+                write!(f, "store for {} is corrupt: {}", hash, message)
+            }
+            SendmeError::Cancelled { transfer_id } => {
+                write!(f, "transfer {} was cancelled", transfer_id)
+            }
+            SendmeError::PathRejected { name, reason } => {
+                write!(f, "collection entry {:?} rejected: {}", name, reason)
+            }
+            SendmeError::UnknownTransfer { id_or_ticket } => {
+                write!(f, "no transfer found for {}", id_or_ticket)
+            }
+            SendmeError::DownloadFailed { node_id, reason } => {
+                write!(f, "download from {} failed: {}", node_id, reason)
+            }
+            SendmeError::TransferFinished { transfer_id, state } => {
+                write!(f, "transfer {} already finished as {:?}", transfer_id, state)
+            }
+            SendmeError::TransferRunning { transfer_id, state } => {
+                write!(f, "transfer {} is still {:?}", transfer_id, state)
+            }
+            SendmeError::NoFreeShortCode { attempts } => {
+                write!(f, "no free short code after {} attempts", attempts)
+            }
+            SendmeError::Other { message } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for SendmeError {}
+
+// The core works with anyhow and attaches typed errors where it knows what
+// went wrong; everything else is classified here on the way out.
+impl From<anyhow::Error> for SendmeError {
+    fn from(error: anyhow::Error) -> Self {
+        let error = match error.downcast::<SendmeError>() {
+            Ok(error) => return error,
+            Err(error) => error,
+        };
+        if let Some(rejected) = error.downcast_ref::<PathRejected>() {
+            return SendmeError::PathRejected {
+                name: rejected.name.clone(),
+                reason: rejected.reason,
+            };
+        }
+        if error.downcast_ref::<std::io::Error>().is_some() {
+            return SendmeError::Io {
+                path: None,
+                message: format!("{:#}", error),
+            };
+        }
+        SendmeError::Other {
+            message: format!("{:#}", error),
+        }
+    }
+}
@@ -2,42 +2,43 @@
 // @generated by `flutter_rust_bridge`@ 2.11.1.
 
 #![allow(
-    non_camel_case_types,
-    unused,
-    non_snake_case,
-    clippy::needless_return,
-    clippy::redundant_closure_call,
-    clippy::redundant_closure,
-    clippy::useless_conversion,
-    clippy::unit_arg,
-    clippy::unused_unit,
-    clippy::double_parens,
-    clippy::let_and_return,
-    clippy::too_many_arguments,
-    clippy::match_single_binding,
-    clippy::clone_on_copy,
-    clippy::let_unit_value,
-    clippy::deref_addrof,
-    clippy::explicit_auto_deref,
-    clippy::borrow_deref_ref,
-    clippy::needless_borrow
+non_camel_case_types,
+unused,
+non_snake_case,
+clippy::needless_return,
+clippy::redundant_closure_call,
+clippy::redundant_closure,
+clippy::useless_conversion,
+clippy::unit_arg,
+clippy::unused_unit,
+clippy::double_parens,
+clippy::let_and_return,
+clippy::too_many_arguments,
+clippy::match_single_binding,
+clippy::clone_on_copy,
+clippy::let_unit_value,
+clippy::deref_addrof,
+clippy::explicit_auto_deref,
+clippy::borrow_deref_ref,
+clippy::needless_borrow
 )]
 
 // Section: imports
 
-use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
-use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
+use flutter_rust_bridge::for_generated::{Lockable, transform_result_dco, Lifetimeable};
+use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, WriteBytesExt, ReadBytesExt};
 
 // Section: boilerplate
 
 flutter_rust_bridge::frb_generated_boilerplate!(
-    default_stream_sink_codec = SseCodec,
-    default_rust_opaque = RustOpaqueMoi,
-    default_rust_auto_opaque = RustAutoOpaqueMoi,
-);
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -2052996009;
+                    default_stream_sink_codec = SseCodec,
+                    default_rust_opaque = RustOpaqueMoi,
+                    default_rust_auto_opaque = RustAutoOpaqueMoi,
+                );
+                pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
+                pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1579572992;
+            
 
 // Section: executor
 
@@ -45,483 +46,2120 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__sendme__format_bytes_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "format_bytes",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_size = <u64>::sse_decode(&mut deserializer);
+fn wire__crate__api__sendme__cancel_transfer_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "cancel_transfer", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u64>::sse_decode(&mut deserializer);
+let api_keep_partial = <bool>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::sendme::cancel_transfer(api_id, api_keep_partial).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__create_receive_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "create_receive", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::sendme::format_bytes(api_size))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__simple__greet_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::sendme::create_receive())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__default_endpoint_config_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "default_endpoint_config", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::sendme::default_endpoint_config())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__default_qr_options_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "default_qr_options", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__simple__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::sendme::default_qr_options())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__delete_partial_download_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "delete_partial_download", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hash = <String>::sse_decode(&mut deserializer);
+let api_temp_dir = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::sendme::delete_partial_download(api_hash, api_temp_dir).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__identity__export_identity_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "export_identity", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::identity::export_identity())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__forget_transfer_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "forget_transfer", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u64>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, crate::error::SendmeError>((move || {
+                     let output_ok = crate::api::sendme::forget_transfer(api_id)?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__format_bytes_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "format_bytes", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_size = <u64>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::sendme::format_bytes(api_size))?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__get_transfer_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_transfer", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u64>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::sendme::get_transfer(api_id))?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__simple__greet_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "greet", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::simple::greet(api_name))?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__identity__import_identity_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "import_identity", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::identity::import_identity(api_secret).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__simple__init_app_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "init_app", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok({ crate::api::simple::init_app(); })?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__identity__init_identity_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "init_identity", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config_dir = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::identity::init_identity(api_config_dir).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__init_logging_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "init_logging", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__sendme__init_logging_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_logging",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+                     let output_ok = Result::<_,()>::Ok({ crate::api::sendme::init_logging(); })?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__node__init_node_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "init_node", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_options = <crate::NodeOptions>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::node::init_node(api_options).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__lan_only_endpoint_config_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "lan_only_endpoint_config", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::sendme::init_logging();
-                })?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__sendme__receive_file_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receive_file",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ticket = <String>::sse_decode(&mut deserializer);
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::sendme::lan_only_endpoint_config())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__list_partial_downloads_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_partial_downloads", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_temp_dir = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::sendme::list_partial_downloads(api_temp_dir).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__list_transfers_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_transfers", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::sendme::receive_file(api_ticket).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__sendme__send_file_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_file",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::sendme::list_transfers())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__identity__node_id_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "node_id", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::sendme::send_file(api_path).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::identity::node_id())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__parse_ticket_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "parse_ticket", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ticket = <String>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, crate::error::SendmeError>((move || {
+                     let output_ok = crate::api::sendme::parse_ticket(api_ticket)?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__progress_stream_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "progress_stream", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<crate::ProgressInfo,flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok({ crate::api::sendme::progress_stream(api_sink); })?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__short_code__publish_short_code_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "publish_short_code", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_transfer_id = <u64>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::short_code::publish_short_code(api_transfer_id).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__qr_code_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "qr_code", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <String>::sse_decode(&mut deserializer);
+let api_options = <crate::QrOptions>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, crate::error::SendmeError>((move || {
+                     let output_ok = crate::api::sendme::qr_code(api_data, api_options)?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__receive_file_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "receive_file", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_transfer_id = <u64>::sse_decode(&mut deserializer);
+let api_ticket = <String>::sse_decode(&mut deserializer);
+let api_options = <crate::ReceiveOptions>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::sendme::receive_file(api_transfer_id, api_ticket, api_options).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__short_code__resolve_short_code_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "resolve_short_code", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_code = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::short_code::resolve_short_code(api_code).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__identity__rotate_identity_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "rotate_identity", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::identity::rotate_identity().await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__send_event_stream_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "send_event_stream", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<crate::SendEvent,flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok({ crate::api::sendme::send_event_stream(api_sink); })?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__send_file_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "send_file", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+let api_options = <crate::SendOptions>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::sendme::send_file(api_path, api_options).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__send_files_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "send_files", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
+let api_options = <crate::SendOptions>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::sendme::send_files(api_paths, api_options).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__short_code__set_code_resolver_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "set_code_resolver", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::CodeResolverConfig>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, crate::error::SendmeError>((move || {
+                     let output_ok = crate::api::short_code::set_code_resolver(api_config)?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__sendme__set_locale_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "set_locale", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_locale = <crate::MessageLocale>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok({ crate::api::sendme::set_locale(api_locale); })?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__node__shutdown_node_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "shutdown_node", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::node::shutdown_node().await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sendme__stop_sharing_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stop_sharing", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ticket_or_id = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::error::SendmeError>((move || async move {
+                         let output_ok = crate::api::sendme::stop_sharing(api_ticket_or_id).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }
 
 // Section: dart2rust
 
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
+
+                impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);}
+                }
+                
+                impl SseDecode for StreamSink<crate::ProgressInfo,flutter_rust_bridge::for_generated::SseCodec> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);}
+                }
+                
+                impl SseDecode for StreamSink<crate::SendEvent,flutter_rust_bridge::for_generated::SseCodec> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);}
+                }
+                
+                impl SseDecode for String {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();}
+                }
+                
+                impl SseDecode for bool {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u8().unwrap() != 0}
+                }
+                
+                impl SseDecode for crate::CodeResolverConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut tag_ = <i32>::sse_decode(deserializer);
+            match tag_ {0 => { return crate::CodeResolverConfig::InProcess; }
+1 => { let mut var_baseUrl = <String>::sse_decode(deserializer);
+return crate::CodeResolverConfig::Http{base_url: var_baseUrl}; }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseDecode for crate::ConflictPolicy {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::ConflictPolicy::Fail,
+1 => crate::ConflictPolicy::Overwrite,
+2 => crate::ConflictPolicy::Skip,
+3 => crate::ConflictPolicy::RenameWithSuffix,
+4 => crate::ConflictPolicy::SkipIfIdenticalHash,
+            _ => unreachable!("Invalid variant for ConflictPolicy: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::ConnectionInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_remoteNodeId = <String>::sse_decode(deserializer);
+let mut var_path = <crate::ConnectionPath>::sse_decode(deserializer);
+let mut var_directAddr = <Option<String>>::sse_decode(deserializer);
+let mut var_relayUrl = <Option<String>>::sse_decode(deserializer);
+let mut var_rttMs = <Option<u64>>::sse_decode(deserializer);
+return crate::ConnectionInfo{remote_node_id: var_remoteNodeId, path: var_path, direct_addr: var_directAddr, relay_url: var_relayUrl, rtt_ms: var_rttMs};}
+                }
+                
+                impl SseDecode for crate::ConnectionPath {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::ConnectionPath::None,
+1 => crate::ConnectionPath::Direct,
+2 => crate::ConnectionPath::Relay,
+3 => crate::ConnectionPath::Mixed,
+            _ => unreachable!("Invalid variant for ConnectionPath: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::EndpointConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_relayMode = <crate::RelayModeOption>::sse_decode(deserializer);
+let mut var_relayUrls = <Vec<String>>::sse_decode(deserializer);
+let mut var_dnsDiscovery = <bool>::sse_decode(deserializer);
+let mut var_pkarrPublishing = <bool>::sse_decode(deserializer);
+let mut var_mdnsDiscovery = <bool>::sse_decode(deserializer);
+let mut var_bindAddr = <Option<String>>::sse_decode(deserializer);
+let mut var_bindPort = <Option<u16>>::sse_decode(deserializer);
+let mut var_lanOnly = <bool>::sse_decode(deserializer);
+let mut var_readyTimeoutMs = <u64>::sse_decode(deserializer);
+return crate::EndpointConfig{relay_mode: var_relayMode, relay_urls: var_relayUrls, dns_discovery: var_dnsDiscovery, pkarr_publishing: var_pkarrPublishing, mdns_discovery: var_mdnsDiscovery, bind_addr: var_bindAddr, bind_port: var_bindPort, lan_only: var_lanOnly, ready_timeout_ms: var_readyTimeoutMs};}
+                }
+                
+                impl SseDecode for i32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_i32::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for Vec<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<String>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::PartialDownload> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::PartialDownload>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<u8> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<u8>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::TransferFile> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::TransferFile>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::TransferInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
-}
-
-impl SseDecode for crate::ReceiveResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_fileCount = <u64>::sse_decode(deserializer);
-        let mut var_size = <u64>::sse_decode(deserializer);
-        let mut var_durationMs = <u64>::sse_decode(deserializer);
-        return crate::ReceiveResult {
-            file_count: var_fileCount,
-            size: var_size,
-            duration_ms: var_durationMs,
-        };
-    }
-}
-
-impl SseDecode for crate::SendResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ticket = <String>::sse_decode(deserializer);
-        let mut var_hash = <String>::sse_decode(deserializer);
-        let mut var_size = <u64>::sse_decode(deserializer);
-        let mut var_fileCount = <u64>::sse_decode(deserializer);
-        return crate::SendResult {
-            ticket: var_ticket,
-            hash: var_hash,
-            size: var_size,
-            file_count: var_fileCount,
-        };
-    }
-}
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
-    }
-}
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
-}
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        3 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__sendme__receive_file_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__sendme__send_file_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
-    }
-}
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__sendme__format_bytes_impl(ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__sendme__init_logging_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
-    }
-}
+        for idx_ in 0..len_ { ans_.push(<crate::TransferInfo>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for crate::MessageLocale {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::MessageLocale::Zh,
+1 => crate::MessageLocale::En,
+            _ => unreachable!("Invalid variant for MessageLocale: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::NodeOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_storeDir = <Option<String>>::sse_decode(deserializer);
+let mut var_endpoint = <crate::EndpointConfig>::sse_decode(deserializer);
+return crate::NodeOptions{store_dir: var_storeDir, endpoint: var_endpoint};}
+                }
+                
+                impl SseDecode for Option<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<String>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::ConnectionInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::ConnectionInfo>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::QrImage> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::QrImage>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::QrOptions> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::QrOptions>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::TicketWarning> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::TicketWarning>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::TransferInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::TransferInfo>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<u16> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<u16>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<u64> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<u64>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for crate::PartialDownload {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_hash = <String>::sse_decode(deserializer);
+let mut var_ticket = <Option<String>>::sse_decode(deserializer);
+let mut var_localBytes = <u64>::sse_decode(deserializer);
+let mut var_path = <String>::sse_decode(deserializer);
+return crate::PartialDownload{hash: var_hash, ticket: var_ticket, local_bytes: var_localBytes, path: var_path};}
+                }
+                
+                impl SseDecode for crate::entry_names::PathRejectReason {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::entry_names::PathRejectReason::Empty,
+1 => crate::entry_names::PathRejectReason::Absolute,
+2 => crate::entry_names::PathRejectReason::DrivePrefix,
+3 => crate::entry_names::PathRejectReason::ParentComponent,
+4 => crate::entry_names::PathRejectReason::NulByte,
+5 => crate::entry_names::PathRejectReason::InvalidCharacter,
+6 => crate::entry_names::PathRejectReason::ReservedName,
+7 => crate::entry_names::PathRejectReason::ComponentTooLong,
+            _ => unreachable!("Invalid variant for PathRejectReason: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::ProgressInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_transferId = <u64>::sse_decode(deserializer);
+let mut var_operation = <crate::ProgressOperation>::sse_decode(deserializer);
+let mut var_stage = <crate::ProgressStage>::sse_decode(deserializer);
+let mut var_current = <u64>::sse_decode(deserializer);
+let mut var_total = <u64>::sse_decode(deserializer);
+let mut var_fileCount = <u64>::sse_decode(deserializer);
+let mut var_filesDone = <u64>::sse_decode(deserializer);
+let mut var_fileName = <Option<String>>::sse_decode(deserializer);
+let mut var_fileCurrent = <u64>::sse_decode(deserializer);
+let mut var_fileTotal = <u64>::sse_decode(deserializer);
+let mut var_bytesPerSecond = <u64>::sse_decode(deserializer);
+let mut var_etaSecs = <Option<u64>>::sse_decode(deserializer);
+let mut var_elapsedMs = <u64>::sse_decode(deserializer);
+let mut var_connection = <Option<crate::ConnectionInfo>>::sse_decode(deserializer);
+let mut var_message = <String>::sse_decode(deserializer);
+return crate::ProgressInfo{transfer_id: var_transferId, operation: var_operation, stage: var_stage, current: var_current, total: var_total, file_count: var_fileCount, files_done: var_filesDone, file_name: var_fileName, file_current: var_fileCurrent, file_total: var_fileTotal, bytes_per_second: var_bytesPerSecond, eta_secs: var_etaSecs, elapsed_ms: var_elapsedMs, connection: var_connection, message: var_message};}
+                }
+                
+                impl SseDecode for crate::ProgressOperation {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::ProgressOperation::Import,
+1 => crate::ProgressOperation::Export,
+2 => crate::ProgressOperation::Download,
+3 => crate::ProgressOperation::Connect,
+            _ => unreachable!("Invalid variant for ProgressOperation: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::ProgressStage {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::ProgressStage::ImportStarted,
+1 => crate::ProgressStage::ImportingFile,
+2 => crate::ProgressStage::FileImported,
+3 => crate::ProgressStage::ImportFinished,
+4 => crate::ProgressStage::WaitingForReceiver,
+5 => crate::ProgressStage::ParsingTicket,
+6 => crate::ProgressStage::Connecting,
+7 => crate::ProgressStage::FetchingInfo,
+8 => crate::ProgressStage::ConnectionChanged,
+9 => crate::ProgressStage::DownloadStarted,
+10 => crate::ProgressStage::DownloadResumed,
+11 => crate::ProgressStage::Downloading,
+12 => crate::ProgressStage::DownloadFinished,
+13 => crate::ProgressStage::ExportingFile,
+14 => crate::ProgressStage::ExportFinished,
+15 => crate::ProgressStage::ReceiveFinished,
+            _ => unreachable!("Invalid variant for ProgressStage: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::QrEcLevel {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::QrEcLevel::L,
+1 => crate::QrEcLevel::M,
+2 => crate::QrEcLevel::Q,
+3 => crate::QrEcLevel::H,
+            _ => unreachable!("Invalid variant for QrEcLevel: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::QrFormat {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::QrFormat::Png,
+1 => crate::QrFormat::Svg,
+            _ => unreachable!("Invalid variant for QrFormat: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::QrImage {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut tag_ = <i32>::sse_decode(deserializer);
+            match tag_ {0 => { let mut var_bytes = <Vec<u8>>::sse_decode(deserializer);
+return crate::QrImage::Png{bytes: var_bytes}; }
+1 => { let mut var_markup = <String>::sse_decode(deserializer);
+return crate::QrImage::Svg{markup: var_markup}; }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseDecode for crate::QrOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_format = <crate::QrFormat>::sse_decode(deserializer);
+let mut var_ecLevel = <crate::QrEcLevel>::sse_decode(deserializer);
+let mut var_moduleSize = <u32>::sse_decode(deserializer);
+return crate::QrOptions{format: var_format, ec_level: var_ecLevel, module_size: var_moduleSize};}
+                }
+                
+                impl SseDecode for crate::ReceiveOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_outputDir = <Option<String>>::sse_decode(deserializer);
+let mut var_tempDir = <Option<String>>::sse_decode(deserializer);
+let mut var_conflictPolicy = <crate::ConflictPolicy>::sse_decode(deserializer);
+let mut var_rawFileName = <Option<String>>::sse_decode(deserializer);
+return crate::ReceiveOptions{output_dir: var_outputDir, temp_dir: var_tempDir, conflict_policy: var_conflictPolicy, raw_file_name: var_rawFileName};}
+                }
+                
+                impl SseDecode for crate::ReceiveResult {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_transferId = <u64>::sse_decode(deserializer);
+let mut var_fileCount = <u64>::sse_decode(deserializer);
+let mut var_size = <u64>::sse_decode(deserializer);
+let mut var_durationMs = <u64>::sse_decode(deserializer);
+let mut var_averageBytesPerSecond = <u64>::sse_decode(deserializer);
+let mut var_peakBytesPerSecond = <u64>::sse_decode(deserializer);
+let mut var_connection = <Option<crate::ConnectionInfo>>::sse_decode(deserializer);
+return crate::ReceiveResult{transfer_id: var_transferId, file_count: var_fileCount, size: var_size, duration_ms: var_durationMs, average_bytes_per_second: var_averageBytesPerSecond, peak_bytes_per_second: var_peakBytesPerSecond, connection: var_connection};}
+                }
+                
+                impl SseDecode for crate::RelayModeOption {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::RelayModeOption::Default,
+1 => crate::RelayModeOption::Custom,
+2 => crate::RelayModeOption::Disabled,
+            _ => unreachable!("Invalid variant for RelayModeOption: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::SendEvent {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_transferId = <u64>::sse_decode(deserializer);
+let mut var_kind = <crate::SendEventKind>::sse_decode(deserializer);
+let mut var_connectionId = <u64>::sse_decode(deserializer);
+let mut var_requestId = <Option<u64>>::sse_decode(deserializer);
+let mut var_nodeId = <Option<String>>::sse_decode(deserializer);
+let mut var_bytesSent = <u64>::sse_decode(deserializer);
+let mut var_total = <u64>::sse_decode(deserializer);
+let mut var_bytesPerSecond = <u64>::sse_decode(deserializer);
+let mut var_etaSecs = <Option<u64>>::sse_decode(deserializer);
+let mut var_elapsedMs = <u64>::sse_decode(deserializer);
+return crate::SendEvent{transfer_id: var_transferId, kind: var_kind, connection_id: var_connectionId, request_id: var_requestId, node_id: var_nodeId, bytes_sent: var_bytesSent, total: var_total, bytes_per_second: var_bytesPerSecond, eta_secs: var_etaSecs, elapsed_ms: var_elapsedMs};}
+                }
+                
+                impl SseDecode for crate::SendEventKind {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::SendEventKind::ClientConnected,
+1 => crate::SendEventKind::RequestReceived,
+2 => crate::SendEventKind::Progress,
+3 => crate::SendEventKind::Completed,
+4 => crate::SendEventKind::Aborted,
+5 => crate::SendEventKind::ClientDisconnected,
+            _ => unreachable!("Invalid variant for SendEventKind: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::SendOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_raw = <bool>::sse_decode(deserializer);
+let mut var_qr = <Option<crate::QrOptions>>::sse_decode(deserializer);
+return crate::SendOptions{raw: var_raw, qr: var_qr};}
+                }
+                
+                impl SseDecode for crate::SendResult {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_transferId = <u64>::sse_decode(deserializer);
+let mut var_ticket = <String>::sse_decode(deserializer);
+let mut var_hash = <String>::sse_decode(deserializer);
+let mut var_size = <u64>::sse_decode(deserializer);
+let mut var_fileCount = <u64>::sse_decode(deserializer);
+let mut var_warning = <Option<crate::TicketWarning>>::sse_decode(deserializer);
+let mut var_qr = <Option<crate::QrImage>>::sse_decode(deserializer);
+return crate::SendResult{transfer_id: var_transferId, ticket: var_ticket, hash: var_hash, size: var_size, file_count: var_fileCount, warning: var_warning, qr: var_qr};}
+                }
+                
+                impl SseDecode for crate::error::SendmeError {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut tag_ = <i32>::sse_decode(deserializer);
+            match tag_ {0 => { let mut var_reason = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::InvalidTicket{reason: var_reason}; }
+1 => { let mut var_code = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::UnknownCode{code: var_code}; }
+2 => { let mut var_reason = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::InvalidIdentity{reason: var_reason}; }
+3 => { let mut var_reason = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::InvalidConfig{reason: var_reason}; }
+4 => { return crate::error::SendmeError::NodeNotStarted; }
+5 => { let mut var_nodeId = <String>::sse_decode(deserializer);
+let mut var_timeoutSecs = <u64>::sse_decode(deserializer);
+return crate::error::SendmeError::ConnectTimeout{node_id: var_nodeId, timeout_secs: var_timeoutSecs}; }
+6 => { let mut var_nodeId = <String>::sse_decode(deserializer);
+let mut var_reason = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::PeerUnreachable{node_id: var_nodeId, reason: var_reason}; }
+7 => { let mut var_path = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::TargetExists{path: var_path}; }
+8 => { let mut var_path = <Option<String>>::sse_decode(deserializer);
+let mut var_message = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::Io{path: var_path, message: var_message}; }
+9 => { let mut var_hash = <String>::sse_decode(deserializer);
+let mut var_message = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::StoreCorrupt{hash: var_hash, message: var_message}; }
+10 => { let mut var_transferId = <u64>::sse_decode(deserializer);
+return crate::error::SendmeError::Cancelled{transfer_id: var_transferId}; }
+11 => { let mut var_name = <String>::sse_decode(deserializer);
+let mut var_reason = <crate::entry_names::PathRejectReason>::sse_decode(deserializer);
+return crate::error::SendmeError::PathRejected{name: var_name, reason: var_reason}; }
+12 => { let mut var_idOrTicket = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::UnknownTransfer{id_or_ticket: var_idOrTicket}; }
+13 => { let mut var_nodeId = <String>::sse_decode(deserializer);
+let mut var_reason = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::DownloadFailed{node_id: var_nodeId, reason: var_reason}; }
+14 => { let mut var_transferId = <u64>::sse_decode(deserializer);
+let mut var_state = <crate::TransferState>::sse_decode(deserializer);
+return crate::error::SendmeError::TransferFinished{transfer_id: var_transferId, state: var_state}; }
+15 => { let mut var_transferId = <u64>::sse_decode(deserializer);
+let mut var_state = <crate::TransferState>::sse_decode(deserializer);
+return crate::error::SendmeError::TransferRunning{transfer_id: var_transferId, state: var_state}; }
+16 => { let mut var_attempts = <u32>::sse_decode(deserializer);
+return crate::error::SendmeError::NoFreeShortCode{attempts: var_attempts}; }
+17 => { let mut var_message = <String>::sse_decode(deserializer);
+return crate::error::SendmeError::Other{message: var_message}; }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseDecode for crate::StopSharingResult {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_transferId = <u64>::sse_decode(deserializer);
+let mut var_connectedReceivers = <u64>::sse_decode(deserializer);
+return crate::StopSharingResult{transfer_id: var_transferId, connected_receivers: var_connectedReceivers};}
+                }
+                
+                impl SseDecode for crate::TicketFormat {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::TicketFormat::Raw,
+1 => crate::TicketFormat::HashSeq,
+            _ => unreachable!("Invalid variant for TicketFormat: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::TicketInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_nodeId = <String>::sse_decode(deserializer);
+let mut var_relayUrl = <Option<String>>::sse_decode(deserializer);
+let mut var_directAddrs = <Vec<String>>::sse_decode(deserializer);
+let mut var_hash = <String>::sse_decode(deserializer);
+let mut var_format = <crate::TicketFormat>::sse_decode(deserializer);
+let mut var_isCollection = <bool>::sse_decode(deserializer);
+let mut var_fileName = <Option<String>>::sse_decode(deserializer);
+return crate::TicketInfo{node_id: var_nodeId, relay_url: var_relayUrl, direct_addrs: var_directAddrs, hash: var_hash, format: var_format, is_collection: var_isCollection, file_name: var_fileName};}
+                }
+                
+                impl SseDecode for crate::TicketWarning {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::TicketWarning::DirectOnly,
+1 => crate::TicketWarning::RelayOnly,
+2 => crate::TicketWarning::NoAddresses,
+            _ => unreachable!("Invalid variant for TicketWarning: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::TransferFile {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_name = <String>::sse_decode(deserializer);
+let mut var_size = <u64>::sse_decode(deserializer);
+return crate::TransferFile{name: var_name, size: var_size};}
+                }
+                
+                impl SseDecode for crate::TransferInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_id = <u64>::sse_decode(deserializer);
+let mut var_kind = <crate::TransferKind>::sse_decode(deserializer);
+let mut var_state = <crate::TransferState>::sse_decode(deserializer);
+let mut var_ticket = <Option<String>>::sse_decode(deserializer);
+let mut var_error = <Option<String>>::sse_decode(deserializer);
+let mut var_files = <Vec<crate::TransferFile>>::sse_decode(deserializer);
+let mut var_shortCode = <Option<String>>::sse_decode(deserializer);
+return crate::TransferInfo{id: var_id, kind: var_kind, state: var_state, ticket: var_ticket, error: var_error, files: var_files, short_code: var_shortCode};}
+                }
+                
+                impl SseDecode for crate::TransferKind {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::TransferKind::Send,
+1 => crate::TransferKind::Receive,
+            _ => unreachable!("Invalid variant for TransferKind: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::TransferState {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::TransferState::Importing,
+1 => crate::TransferState::Waiting,
+2 => crate::TransferState::Connected,
+3 => crate::TransferState::Transferring,
+4 => crate::TransferState::Exporting,
+5 => crate::TransferState::Done,
+6 => crate::TransferState::Failed,
+7 => crate::TransferState::Cancelled,
+            _ => unreachable!("Invalid variant for TransferState: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for u16 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u16::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for u32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u32::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for u64 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u64::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for u8 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u8().unwrap()}
+                }
+                
+                impl SseDecode for () {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+                }
+                
+                fn pde_ffi_dispatcher_primary_impl(
+                    func_id: i32,port: flutter_rust_bridge::for_generated::MessagePort,
+                    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+                    rust_vec_len: i32,
+                    data_len: i32,
+                )  {
+                    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+                    match func_id {
+                        1 => wire__crate__api__sendme__cancel_transfer_impl(port, ptr, rust_vec_len, data_len),
+5 => wire__crate__api__sendme__delete_partial_download_impl(port, ptr, rust_vec_len, data_len),
+11 => wire__crate__api__identity__import_identity_impl(port, ptr, rust_vec_len, data_len),
+12 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+13 => wire__crate__api__identity__init_identity_impl(port, ptr, rust_vec_len, data_len),
+15 => wire__crate__api__node__init_node_impl(port, ptr, rust_vec_len, data_len),
+17 => wire__crate__api__sendme__list_partial_downloads_impl(port, ptr, rust_vec_len, data_len),
+22 => wire__crate__api__short_code__publish_short_code_impl(port, ptr, rust_vec_len, data_len),
+24 => wire__crate__api__sendme__receive_file_impl(port, ptr, rust_vec_len, data_len),
+25 => wire__crate__api__short_code__resolve_short_code_impl(port, ptr, rust_vec_len, data_len),
+26 => wire__crate__api__identity__rotate_identity_impl(port, ptr, rust_vec_len, data_len),
+28 => wire__crate__api__sendme__send_file_impl(port, ptr, rust_vec_len, data_len),
+29 => wire__crate__api__sendme__send_files_impl(port, ptr, rust_vec_len, data_len),
+32 => wire__crate__api__node__shutdown_node_impl(port, ptr, rust_vec_len, data_len),
+33 => wire__crate__api__sendme__stop_sharing_impl(port, ptr, rust_vec_len, data_len),
+                        _ => unreachable!(),
+                    }
+                }
+                
+                fn pde_ffi_dispatcher_sync_impl(
+                    func_id: i32,
+                    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+                    rust_vec_len: i32,
+                    data_len: i32,
+                ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+                    match func_id {
+                        2 => wire__crate__api__sendme__create_receive_impl(ptr, rust_vec_len, data_len),
+3 => wire__crate__api__sendme__default_endpoint_config_impl(ptr, rust_vec_len, data_len),
+4 => wire__crate__api__sendme__default_qr_options_impl(ptr, rust_vec_len, data_len),
+6 => wire__crate__api__identity__export_identity_impl(ptr, rust_vec_len, data_len),
+7 => wire__crate__api__sendme__forget_transfer_impl(ptr, rust_vec_len, data_len),
+8 => wire__crate__api__sendme__format_bytes_impl(ptr, rust_vec_len, data_len),
+9 => wire__crate__api__sendme__get_transfer_impl(ptr, rust_vec_len, data_len),
+10 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+14 => wire__crate__api__sendme__init_logging_impl(ptr, rust_vec_len, data_len),
+16 => wire__crate__api__sendme__lan_only_endpoint_config_impl(ptr, rust_vec_len, data_len),
+18 => wire__crate__api__sendme__list_transfers_impl(ptr, rust_vec_len, data_len),
+19 => wire__crate__api__identity__node_id_impl(ptr, rust_vec_len, data_len),
+20 => wire__crate__api__sendme__parse_ticket_impl(ptr, rust_vec_len, data_len),
+21 => wire__crate__api__sendme__progress_stream_impl(ptr, rust_vec_len, data_len),
+23 => wire__crate__api__sendme__qr_code_impl(ptr, rust_vec_len, data_len),
+27 => wire__crate__api__sendme__send_event_stream_impl(ptr, rust_vec_len, data_len),
+30 => wire__crate__api__short_code__set_code_resolver_impl(ptr, rust_vec_len, data_len),
+31 => wire__crate__api__sendme__set_locale_impl(ptr, rust_vec_len, data_len),
+                        _ => unreachable!(),
+                    }
+                }
+                
 
 // Section: rust2dart
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::ReceiveResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.file_count.into_into_dart().into_dart(),
-            self.size.into_into_dart().into_dart(),
-            self.duration_ms.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ReceiveResult {}
+            impl flutter_rust_bridge::IntoDart for crate::CodeResolverConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {crate::CodeResolverConfig::InProcess => { [0.into_dart()].into_dart() }
+crate::CodeResolverConfig::Http{base_url} => { [1.into_dart(),
+base_url.into_into_dart().into_dart()].into_dart() }
+ _ => { unimplemented!(""); }}
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::CodeResolverConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::CodeResolverConfig> for crate::CodeResolverConfig {
+            fn into_into_dart(self) -> crate::CodeResolverConfig {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ConflictPolicy {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Fail => 0.into_dart(),
+Self::Overwrite => 1.into_dart(),
+Self::Skip => 2.into_dart(),
+Self::RenameWithSuffix => 3.into_dart(),
+Self::SkipIfIdenticalHash => 4.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ConflictPolicy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ConflictPolicy> for crate::ConflictPolicy {
+            fn into_into_dart(self) -> crate::ConflictPolicy {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ConnectionInfo {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.remote_node_id.into_into_dart().into_dart(),
+self.path.into_into_dart().into_dart(),
+self.direct_addr.into_into_dart().into_dart(),
+self.relay_url.into_into_dart().into_dart(),
+self.rtt_ms.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ConnectionInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ConnectionInfo> for crate::ConnectionInfo {
+            fn into_into_dart(self) -> crate::ConnectionInfo {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ConnectionPath {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::None => 0.into_dart(),
+Self::Direct => 1.into_dart(),
+Self::Relay => 2.into_dart(),
+Self::Mixed => 3.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ConnectionPath {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ConnectionPath> for crate::ConnectionPath {
+            fn into_into_dart(self) -> crate::ConnectionPath {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::EndpointConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.relay_mode.into_into_dart().into_dart(),
+self.relay_urls.into_into_dart().into_dart(),
+self.dns_discovery.into_into_dart().into_dart(),
+self.pkarr_publishing.into_into_dart().into_dart(),
+self.mdns_discovery.into_into_dart().into_dart(),
+self.bind_addr.into_into_dart().into_dart(),
+self.bind_port.into_into_dart().into_dart(),
+self.lan_only.into_into_dart().into_dart(),
+self.ready_timeout_ms.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::EndpointConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::EndpointConfig> for crate::EndpointConfig {
+            fn into_into_dart(self) -> crate::EndpointConfig {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::MessageLocale {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Zh => 0.into_dart(),
+Self::En => 1.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::MessageLocale {}
+impl flutter_rust_bridge::IntoIntoDart<crate::MessageLocale> for crate::MessageLocale {
+            fn into_into_dart(self) -> crate::MessageLocale {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::NodeOptions {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.store_dir.into_into_dart().into_dart(),
+self.endpoint.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::NodeOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::NodeOptions> for crate::NodeOptions {
+            fn into_into_dart(self) -> crate::NodeOptions {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::PartialDownload {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.hash.into_into_dart().into_dart(),
+self.ticket.into_into_dart().into_dart(),
+self.local_bytes.into_into_dart().into_dart(),
+self.path.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::PartialDownload {}
+impl flutter_rust_bridge::IntoIntoDart<crate::PartialDownload> for crate::PartialDownload {
+            fn into_into_dart(self) -> crate::PartialDownload {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::entry_names::PathRejectReason {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Empty => 0.into_dart(),
+Self::Absolute => 1.into_dart(),
+Self::DrivePrefix => 2.into_dart(),
+Self::ParentComponent => 3.into_dart(),
+Self::NulByte => 4.into_dart(),
+Self::InvalidCharacter => 5.into_dart(),
+Self::ReservedName => 6.into_dart(),
+Self::ComponentTooLong => 7.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::entry_names::PathRejectReason {}
+impl flutter_rust_bridge::IntoIntoDart<crate::entry_names::PathRejectReason> for crate::entry_names::PathRejectReason {
+            fn into_into_dart(self) -> crate::entry_names::PathRejectReason {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ProgressInfo {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.transfer_id.into_into_dart().into_dart(),
+self.operation.into_into_dart().into_dart(),
+self.stage.into_into_dart().into_dart(),
+self.current.into_into_dart().into_dart(),
+self.total.into_into_dart().into_dart(),
+self.file_count.into_into_dart().into_dart(),
+self.files_done.into_into_dart().into_dart(),
+self.file_name.into_into_dart().into_dart(),
+self.file_current.into_into_dart().into_dart(),
+self.file_total.into_into_dart().into_dart(),
+self.bytes_per_second.into_into_dart().into_dart(),
+self.eta_secs.into_into_dart().into_dart(),
+self.elapsed_ms.into_into_dart().into_dart(),
+self.connection.into_into_dart().into_dart(),
+self.message.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ProgressInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ProgressInfo> for crate::ProgressInfo {
+            fn into_into_dart(self) -> crate::ProgressInfo {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ProgressOperation {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Import => 0.into_dart(),
+Self::Export => 1.into_dart(),
+Self::Download => 2.into_dart(),
+Self::Connect => 3.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ProgressOperation {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ProgressOperation> for crate::ProgressOperation {
+            fn into_into_dart(self) -> crate::ProgressOperation {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ProgressStage {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::ImportStarted => 0.into_dart(),
+Self::ImportingFile => 1.into_dart(),
+Self::FileImported => 2.into_dart(),
+Self::ImportFinished => 3.into_dart(),
+Self::WaitingForReceiver => 4.into_dart(),
+Self::ParsingTicket => 5.into_dart(),
+Self::Connecting => 6.into_dart(),
+Self::FetchingInfo => 7.into_dart(),
+Self::ConnectionChanged => 8.into_dart(),
+Self::DownloadStarted => 9.into_dart(),
+Self::DownloadResumed => 10.into_dart(),
+Self::Downloading => 11.into_dart(),
+Self::DownloadFinished => 12.into_dart(),
+Self::ExportingFile => 13.into_dart(),
+Self::ExportFinished => 14.into_dart(),
+Self::ReceiveFinished => 15.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ProgressStage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ProgressStage> for crate::ProgressStage {
+            fn into_into_dart(self) -> crate::ProgressStage {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::QrEcLevel {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::L => 0.into_dart(),
+Self::M => 1.into_dart(),
+Self::Q => 2.into_dart(),
+Self::H => 3.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::QrEcLevel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::QrEcLevel> for crate::QrEcLevel {
+            fn into_into_dart(self) -> crate::QrEcLevel {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::QrFormat {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Png => 0.into_dart(),
+Self::Svg => 1.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::QrFormat {}
+impl flutter_rust_bridge::IntoIntoDart<crate::QrFormat> for crate::QrFormat {
+            fn into_into_dart(self) -> crate::QrFormat {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::QrImage {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {crate::QrImage::Png{bytes} => { [0.into_dart(),
+bytes.into_into_dart().into_dart()].into_dart() }
+crate::QrImage::Svg{markup} => { [1.into_dart(),
+markup.into_into_dart().into_dart()].into_dart() }
+ _ => { unimplemented!(""); }}
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::QrImage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::QrImage> for crate::QrImage {
+            fn into_into_dart(self) -> crate::QrImage {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::QrOptions {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.format.into_into_dart().into_dart(),
+self.ec_level.into_into_dart().into_dart(),
+self.module_size.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::QrOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::QrOptions> for crate::QrOptions {
+            fn into_into_dart(self) -> crate::QrOptions {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ReceiveOptions {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.output_dir.into_into_dart().into_dart(),
+self.temp_dir.into_into_dart().into_dart(),
+self.conflict_policy.into_into_dart().into_dart(),
+self.raw_file_name.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ReceiveOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::ReceiveOptions> for crate::ReceiveOptions {
+            fn into_into_dart(self) -> crate::ReceiveOptions {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::ReceiveResult {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.transfer_id.into_into_dart().into_dart(),
+self.file_count.into_into_dart().into_dart(),
+self.size.into_into_dart().into_dart(),
+self.duration_ms.into_into_dart().into_dart(),
+self.average_bytes_per_second.into_into_dart().into_dart(),
+self.peak_bytes_per_second.into_into_dart().into_dart(),
+self.connection.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::ReceiveResult {}
 impl flutter_rust_bridge::IntoIntoDart<crate::ReceiveResult> for crate::ReceiveResult {
-    fn into_into_dart(self) -> crate::ReceiveResult {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::SendResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ticket.into_into_dart().into_dart(),
-            self.hash.into_into_dart().into_dart(),
-            self.size.into_into_dart().into_dart(),
-            self.file_count.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::SendResult {}
+            fn into_into_dart(self) -> crate::ReceiveResult {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::RelayModeOption {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Default => 0.into_dart(),
+Self::Custom => 1.into_dart(),
+Self::Disabled => 2.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::RelayModeOption {}
+impl flutter_rust_bridge::IntoIntoDart<crate::RelayModeOption> for crate::RelayModeOption {
+            fn into_into_dart(self) -> crate::RelayModeOption {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::SendEvent {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.transfer_id.into_into_dart().into_dart(),
+self.kind.into_into_dart().into_dart(),
+self.connection_id.into_into_dart().into_dart(),
+self.request_id.into_into_dart().into_dart(),
+self.node_id.into_into_dart().into_dart(),
+self.bytes_sent.into_into_dart().into_dart(),
+self.total.into_into_dart().into_dart(),
+self.bytes_per_second.into_into_dart().into_dart(),
+self.eta_secs.into_into_dart().into_dart(),
+self.elapsed_ms.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::SendEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::SendEvent> for crate::SendEvent {
+            fn into_into_dart(self) -> crate::SendEvent {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::SendEventKind {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::ClientConnected => 0.into_dart(),
+Self::RequestReceived => 1.into_dart(),
+Self::Progress => 2.into_dart(),
+Self::Completed => 3.into_dart(),
+Self::Aborted => 4.into_dart(),
+Self::ClientDisconnected => 5.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::SendEventKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::SendEventKind> for crate::SendEventKind {
+            fn into_into_dart(self) -> crate::SendEventKind {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::SendOptions {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.raw.into_into_dart().into_dart(),
+self.qr.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::SendOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::SendOptions> for crate::SendOptions {
+            fn into_into_dart(self) -> crate::SendOptions {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::SendResult {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.transfer_id.into_into_dart().into_dart(),
+self.ticket.into_into_dart().into_dart(),
+self.hash.into_into_dart().into_dart(),
+self.size.into_into_dart().into_dart(),
+self.file_count.into_into_dart().into_dart(),
+self.warning.into_into_dart().into_dart(),
+self.qr.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::SendResult {}
 impl flutter_rust_bridge::IntoIntoDart<crate::SendResult> for crate::SendResult {
-    fn into_into_dart(self) -> crate::SendResult {
-        self
-    }
-}
-
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
-    }
-}
-
-impl SseEncode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
-    }
-}
-
-impl SseEncode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
-    }
-}
-
-impl SseEncode for crate::ReceiveResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.file_count, serializer);
-        <u64>::sse_encode(self.size, serializer);
-        <u64>::sse_encode(self.duration_ms, serializer);
-    }
-}
-
-impl SseEncode for crate::SendResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.ticket, serializer);
-        <String>::sse_encode(self.hash, serializer);
-        <u64>::sse_encode(self.size, serializer);
-        <u64>::sse_encode(self.file_count, serializer);
-    }
-}
-
-impl SseEncode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
-    }
-}
-
-impl SseEncode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
-    }
-}
-
-impl SseEncode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
-}
-
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
-    }
-}
-
-impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
-    }
-}
-
-#[cfg(not(target_family = "wasm"))]
-mod io {
-    // This file is automatically generated, so please do not edit it.
-    // @generated by `flutter_rust_bridge`@ 2.11.1.
-
-    // Section: imports
-
-    use super::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
-    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
-    use flutter_rust_bridge::{Handler, IntoIntoDart};
-
-    // Section: boilerplate
-
-    flutter_rust_bridge::frb_generated_boilerplate_io!();
-}
-#[cfg(not(target_family = "wasm"))]
-pub use io::*;
-
-/// cbindgen:ignore
-#[cfg(target_family = "wasm")]
-mod web {
-    // This file is automatically generated, so please do not edit it.
-    // @generated by `flutter_rust_bridge`@ 2.11.1.
-
-    // Section: imports
-
-    use super::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
-    use flutter_rust_bridge::for_generated::wasm_bindgen;
-    use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
-    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
-    use flutter_rust_bridge::{Handler, IntoIntoDart};
-
-    // Section: boilerplate
-
-    flutter_rust_bridge::frb_generated_boilerplate_web!();
-}
-#[cfg(target_family = "wasm")]
-pub use web::*;
+            fn into_into_dart(self) -> crate::SendResult {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::error::SendmeError {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {crate::error::SendmeError::InvalidTicket{reason} => { [0.into_dart(),
+reason.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::UnknownCode{code} => { [1.into_dart(),
+code.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::InvalidIdentity{reason} => { [2.into_dart(),
+reason.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::InvalidConfig{reason} => { [3.into_dart(),
+reason.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::NodeNotStarted => { [4.into_dart()].into_dart() }
+crate::error::SendmeError::ConnectTimeout{node_id,timeout_secs} => { [5.into_dart(),
+node_id.into_into_dart().into_dart(),
+timeout_secs.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::PeerUnreachable{node_id,reason} => { [6.into_dart(),
+node_id.into_into_dart().into_dart(),
+reason.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::TargetExists{path} => { [7.into_dart(),
+path.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::Io{path,message} => { [8.into_dart(),
+path.into_into_dart().into_dart(),
+message.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::StoreCorrupt{hash,message} => { [9.into_dart(),
+hash.into_into_dart().into_dart(),
+message.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::Cancelled{transfer_id} => { [10.into_dart(),
+transfer_id.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::PathRejected{name,reason} => { [11.into_dart(),
+name.into_into_dart().into_dart(),
+reason.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::UnknownTransfer{id_or_ticket} => { [12.into_dart(),
+id_or_ticket.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::DownloadFailed{node_id,reason} => { [13.into_dart(),
+node_id.into_into_dart().into_dart(),
+reason.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::TransferFinished{transfer_id,state} => { [14.into_dart(),
+transfer_id.into_into_dart().into_dart(),
+state.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::TransferRunning{transfer_id,state} => { [15.into_dart(),
+transfer_id.into_into_dart().into_dart(),
+state.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::NoFreeShortCode{attempts} => { [16.into_dart(),
+attempts.into_into_dart().into_dart()].into_dart() }
+crate::error::SendmeError::Other{message} => { [17.into_dart(),
+message.into_into_dart().into_dart()].into_dart() }
+ _ => { unimplemented!(""); }}
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::SendmeError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::SendmeError> for crate::error::SendmeError {
+            fn into_into_dart(self) -> crate::error::SendmeError {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::StopSharingResult {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.transfer_id.into_into_dart().into_dart(),
+self.connected_receivers.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::StopSharingResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::StopSharingResult> for crate::StopSharingResult {
+            fn into_into_dart(self) -> crate::StopSharingResult {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::TicketFormat {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Raw => 0.into_dart(),
+Self::HashSeq => 1.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::TicketFormat {}
+impl flutter_rust_bridge::IntoIntoDart<crate::TicketFormat> for crate::TicketFormat {
+            fn into_into_dart(self) -> crate::TicketFormat {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::TicketInfo {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.node_id.into_into_dart().into_dart(),
+self.relay_url.into_into_dart().into_dart(),
+self.direct_addrs.into_into_dart().into_dart(),
+self.hash.into_into_dart().into_dart(),
+self.format.into_into_dart().into_dart(),
+self.is_collection.into_into_dart().into_dart(),
+self.file_name.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::TicketInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::TicketInfo> for crate::TicketInfo {
+            fn into_into_dart(self) -> crate::TicketInfo {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::TicketWarning {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::DirectOnly => 0.into_dart(),
+Self::RelayOnly => 1.into_dart(),
+Self::NoAddresses => 2.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::TicketWarning {}
+impl flutter_rust_bridge::IntoIntoDart<crate::TicketWarning> for crate::TicketWarning {
+            fn into_into_dart(self) -> crate::TicketWarning {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::TransferFile {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.name.into_into_dart().into_dart(),
+self.size.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::TransferFile {}
+impl flutter_rust_bridge::IntoIntoDart<crate::TransferFile> for crate::TransferFile {
+            fn into_into_dart(self) -> crate::TransferFile {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::TransferInfo {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.id.into_into_dart().into_dart(),
+self.kind.into_into_dart().into_dart(),
+self.state.into_into_dart().into_dart(),
+self.ticket.into_into_dart().into_dart(),
+self.error.into_into_dart().into_dart(),
+self.files.into_into_dart().into_dart(),
+self.short_code.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::TransferInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::TransferInfo> for crate::TransferInfo {
+            fn into_into_dart(self) -> crate::TransferInfo {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::TransferKind {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Send => 0.into_dart(),
+Self::Receive => 1.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::TransferKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::TransferKind> for crate::TransferKind {
+            fn into_into_dart(self) -> crate::TransferKind {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::TransferState {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Importing => 0.into_dart(),
+Self::Waiting => 1.into_dart(),
+Self::Connected => 2.into_dart(),
+Self::Transferring => 3.into_dart(),
+Self::Exporting => 4.into_dart(),
+Self::Done => 5.into_dart(),
+Self::Failed => 6.into_dart(),
+Self::Cancelled => 7.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::TransferState {}
+impl flutter_rust_bridge::IntoIntoDart<crate::TransferState> for crate::TransferState {
+            fn into_into_dart(self) -> crate::TransferState {
+                self
+            }
+        }
+
+                impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(format!("{:?}", self), serializer);}
+                }
+                
+                impl SseEncode for StreamSink<crate::ProgressInfo,flutter_rust_bridge::for_generated::SseCodec> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {unimplemented!("")}
+                }
+                
+                impl SseEncode for StreamSink<crate::SendEvent,flutter_rust_bridge::for_generated::SseCodec> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {unimplemented!("")}
+                }
+                
+                impl SseEncode for String {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<u8>>::sse_encode(self.into_bytes(), serializer);}
+                }
+                
+                impl SseEncode for bool {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u8(self as _).unwrap();}
+                }
+                
+                impl SseEncode for crate::CodeResolverConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {match self {crate::CodeResolverConfig::InProcess => { <i32>::sse_encode(0, serializer);  }
+crate::CodeResolverConfig::Http{base_url} => { <i32>::sse_encode(1, serializer); <String>::sse_encode(base_url, serializer);
+ }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseEncode for crate::ConflictPolicy {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::ConflictPolicy::Fail => { 0 }
+crate::ConflictPolicy::Overwrite => { 1 }
+crate::ConflictPolicy::Skip => { 2 }
+crate::ConflictPolicy::RenameWithSuffix => { 3 }
+crate::ConflictPolicy::SkipIfIdenticalHash => { 4 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::ConnectionInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.remote_node_id, serializer);
+<crate::ConnectionPath>::sse_encode(self.path, serializer);
+<Option<String>>::sse_encode(self.direct_addr, serializer);
+<Option<String>>::sse_encode(self.relay_url, serializer);
+<Option<u64>>::sse_encode(self.rtt_ms, serializer);}
+                }
+                
+                impl SseEncode for crate::ConnectionPath {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::ConnectionPath::None => { 0 }
+crate::ConnectionPath::Direct => { 1 }
+crate::ConnectionPath::Relay => { 2 }
+crate::ConnectionPath::Mixed => { 3 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::EndpointConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::RelayModeOption>::sse_encode(self.relay_mode, serializer);
+<Vec<String>>::sse_encode(self.relay_urls, serializer);
+<bool>::sse_encode(self.dns_discovery, serializer);
+<bool>::sse_encode(self.pkarr_publishing, serializer);
+<bool>::sse_encode(self.mdns_discovery, serializer);
+<Option<String>>::sse_encode(self.bind_addr, serializer);
+<Option<u16>>::sse_encode(self.bind_port, serializer);
+<bool>::sse_encode(self.lan_only, serializer);
+<u64>::sse_encode(self.ready_timeout_ms, serializer);}
+                }
+                
+                impl SseEncode for i32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_i32::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for Vec<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <String>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::PartialDownload> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::PartialDownload>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<u8> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <u8>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::TransferFile> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::TransferFile>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::TransferInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::TransferInfo>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for crate::MessageLocale {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::MessageLocale::Zh => { 0 }
+crate::MessageLocale::En => { 1 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::NodeOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Option<String>>::sse_encode(self.store_dir, serializer);
+<crate::EndpointConfig>::sse_encode(self.endpoint, serializer);}
+                }
+                
+                impl SseEncode for Option<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <String>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::ConnectionInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::ConnectionInfo>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::QrImage> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::QrImage>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::QrOptions> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::QrOptions>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::TicketWarning> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::TicketWarning>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::TransferInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::TransferInfo>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<u16> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <u16>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<u64> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <u64>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for crate::PartialDownload {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.hash, serializer);
+<Option<String>>::sse_encode(self.ticket, serializer);
+<u64>::sse_encode(self.local_bytes, serializer);
+<String>::sse_encode(self.path, serializer);}
+                }
+                
+                impl SseEncode for crate::entry_names::PathRejectReason {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::entry_names::PathRejectReason::Empty => { 0 }
+crate::entry_names::PathRejectReason::Absolute => { 1 }
+crate::entry_names::PathRejectReason::DrivePrefix => { 2 }
+crate::entry_names::PathRejectReason::ParentComponent => { 3 }
+crate::entry_names::PathRejectReason::NulByte => { 4 }
+crate::entry_names::PathRejectReason::InvalidCharacter => { 5 }
+crate::entry_names::PathRejectReason::ReservedName => { 6 }
+crate::entry_names::PathRejectReason::ComponentTooLong => { 7 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::ProgressInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.transfer_id, serializer);
+<crate::ProgressOperation>::sse_encode(self.operation, serializer);
+<crate::ProgressStage>::sse_encode(self.stage, serializer);
+<u64>::sse_encode(self.current, serializer);
+<u64>::sse_encode(self.total, serializer);
+<u64>::sse_encode(self.file_count, serializer);
+<u64>::sse_encode(self.files_done, serializer);
+<Option<String>>::sse_encode(self.file_name, serializer);
+<u64>::sse_encode(self.file_current, serializer);
+<u64>::sse_encode(self.file_total, serializer);
+<u64>::sse_encode(self.bytes_per_second, serializer);
+<Option<u64>>::sse_encode(self.eta_secs, serializer);
+<u64>::sse_encode(self.elapsed_ms, serializer);
+<Option<crate::ConnectionInfo>>::sse_encode(self.connection, serializer);
+<String>::sse_encode(self.message, serializer);}
+                }
+                
+                impl SseEncode for crate::ProgressOperation {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::ProgressOperation::Import => { 0 }
+crate::ProgressOperation::Export => { 1 }
+crate::ProgressOperation::Download => { 2 }
+crate::ProgressOperation::Connect => { 3 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::ProgressStage {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::ProgressStage::ImportStarted => { 0 }
+crate::ProgressStage::ImportingFile => { 1 }
+crate::ProgressStage::FileImported => { 2 }
+crate::ProgressStage::ImportFinished => { 3 }
+crate::ProgressStage::WaitingForReceiver => { 4 }
+crate::ProgressStage::ParsingTicket => { 5 }
+crate::ProgressStage::Connecting => { 6 }
+crate::ProgressStage::FetchingInfo => { 7 }
+crate::ProgressStage::ConnectionChanged => { 8 }
+crate::ProgressStage::DownloadStarted => { 9 }
+crate::ProgressStage::DownloadResumed => { 10 }
+crate::ProgressStage::Downloading => { 11 }
+crate::ProgressStage::DownloadFinished => { 12 }
+crate::ProgressStage::ExportingFile => { 13 }
+crate::ProgressStage::ExportFinished => { 14 }
+crate::ProgressStage::ReceiveFinished => { 15 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::QrEcLevel {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::QrEcLevel::L => { 0 }
+crate::QrEcLevel::M => { 1 }
+crate::QrEcLevel::Q => { 2 }
+crate::QrEcLevel::H => { 3 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::QrFormat {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::QrFormat::Png => { 0 }
+crate::QrFormat::Svg => { 1 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::QrImage {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {match self {crate::QrImage::Png{bytes} => { <i32>::sse_encode(0, serializer); <Vec<u8>>::sse_encode(bytes, serializer);
+ }
+crate::QrImage::Svg{markup} => { <i32>::sse_encode(1, serializer); <String>::sse_encode(markup, serializer);
+ }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseEncode for crate::QrOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::QrFormat>::sse_encode(self.format, serializer);
+<crate::QrEcLevel>::sse_encode(self.ec_level, serializer);
+<u32>::sse_encode(self.module_size, serializer);}
+                }
+                
+                impl SseEncode for crate::ReceiveOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Option<String>>::sse_encode(self.output_dir, serializer);
+<Option<String>>::sse_encode(self.temp_dir, serializer);
+<crate::ConflictPolicy>::sse_encode(self.conflict_policy, serializer);
+<Option<String>>::sse_encode(self.raw_file_name, serializer);}
+                }
+                
+                impl SseEncode for crate::ReceiveResult {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.transfer_id, serializer);
+<u64>::sse_encode(self.file_count, serializer);
+<u64>::sse_encode(self.size, serializer);
+<u64>::sse_encode(self.duration_ms, serializer);
+<u64>::sse_encode(self.average_bytes_per_second, serializer);
+<u64>::sse_encode(self.peak_bytes_per_second, serializer);
+<Option<crate::ConnectionInfo>>::sse_encode(self.connection, serializer);}
+                }
+                
+                impl SseEncode for crate::RelayModeOption {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::RelayModeOption::Default => { 0 }
+crate::RelayModeOption::Custom => { 1 }
+crate::RelayModeOption::Disabled => { 2 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::SendEvent {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.transfer_id, serializer);
+<crate::SendEventKind>::sse_encode(self.kind, serializer);
+<u64>::sse_encode(self.connection_id, serializer);
+<Option<u64>>::sse_encode(self.request_id, serializer);
+<Option<String>>::sse_encode(self.node_id, serializer);
+<u64>::sse_encode(self.bytes_sent, serializer);
+<u64>::sse_encode(self.total, serializer);
+<u64>::sse_encode(self.bytes_per_second, serializer);
+<Option<u64>>::sse_encode(self.eta_secs, serializer);
+<u64>::sse_encode(self.elapsed_ms, serializer);}
+                }
+                
+                impl SseEncode for crate::SendEventKind {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::SendEventKind::ClientConnected => { 0 }
+crate::SendEventKind::RequestReceived => { 1 }
+crate::SendEventKind::Progress => { 2 }
+crate::SendEventKind::Completed => { 3 }
+crate::SendEventKind::Aborted => { 4 }
+crate::SendEventKind::ClientDisconnected => { 5 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::SendOptions {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.raw, serializer);
+<Option<crate::QrOptions>>::sse_encode(self.qr, serializer);}
+                }
+                
+                impl SseEncode for crate::SendResult {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.transfer_id, serializer);
+<String>::sse_encode(self.ticket, serializer);
+<String>::sse_encode(self.hash, serializer);
+<u64>::sse_encode(self.size, serializer);
+<u64>::sse_encode(self.file_count, serializer);
+<Option<crate::TicketWarning>>::sse_encode(self.warning, serializer);
+<Option<crate::QrImage>>::sse_encode(self.qr, serializer);}
+                }
+                
+                impl SseEncode for crate::error::SendmeError {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {match self {crate::error::SendmeError::InvalidTicket{reason} => { <i32>::sse_encode(0, serializer); <String>::sse_encode(reason, serializer);
+ }
+crate::error::SendmeError::UnknownCode{code} => { <i32>::sse_encode(1, serializer); <String>::sse_encode(code, serializer);
+ }
+crate::error::SendmeError::InvalidIdentity{reason} => { <i32>::sse_encode(2, serializer); <String>::sse_encode(reason, serializer);
+ }
+crate::error::SendmeError::InvalidConfig{reason} => { <i32>::sse_encode(3, serializer); <String>::sse_encode(reason, serializer);
+ }
+crate::error::SendmeError::NodeNotStarted => { <i32>::sse_encode(4, serializer);  }
+crate::error::SendmeError::ConnectTimeout{node_id,timeout_secs} => { <i32>::sse_encode(5, serializer); <String>::sse_encode(node_id, serializer);
+<u64>::sse_encode(timeout_secs, serializer);
+ }
+crate::error::SendmeError::PeerUnreachable{node_id,reason} => { <i32>::sse_encode(6, serializer); <String>::sse_encode(node_id, serializer);
+<String>::sse_encode(reason, serializer);
+ }
+crate::error::SendmeError::TargetExists{path} => { <i32>::sse_encode(7, serializer); <String>::sse_encode(path, serializer);
+ }
+crate::error::SendmeError::Io{path,message} => { <i32>::sse_encode(8, serializer); <Option<String>>::sse_encode(path, serializer);
+<String>::sse_encode(message, serializer);
+ }
+crate::error::SendmeError::StoreCorrupt{hash,message} => { <i32>::sse_encode(9, serializer); <String>::sse_encode(hash, serializer);
+<String>::sse_encode(message, serializer);
+ }
+crate::error::SendmeError::Cancelled{transfer_id} => { <i32>::sse_encode(10, serializer); <u64>::sse_encode(transfer_id, serializer);
+ }
+crate::error::SendmeError::PathRejected{name,reason} => { <i32>::sse_encode(11, serializer); <String>::sse_encode(name, serializer);
+<crate::entry_names::PathRejectReason>::sse_encode(reason, serializer);
+ }
+crate::error::SendmeError::UnknownTransfer{id_or_ticket} => { <i32>::sse_encode(12, serializer); <String>::sse_encode(id_or_ticket, serializer);
+ }
+crate::error::SendmeError::DownloadFailed{node_id,reason} => { <i32>::sse_encode(13, serializer); <String>::sse_encode(node_id, serializer);
+<String>::sse_encode(reason, serializer);
+ }
+crate::error::SendmeError::TransferFinished{transfer_id,state} => { <i32>::sse_encode(14, serializer); <u64>::sse_encode(transfer_id, serializer);
+<crate::TransferState>::sse_encode(state, serializer);
+ }
+crate::error::SendmeError::TransferRunning{transfer_id,state} => { <i32>::sse_encode(15, serializer); <u64>::sse_encode(transfer_id, serializer);
+<crate::TransferState>::sse_encode(state, serializer);
+ }
+crate::error::SendmeError::NoFreeShortCode{attempts} => { <i32>::sse_encode(16, serializer); <u32>::sse_encode(attempts, serializer);
+ }
+crate::error::SendmeError::Other{message} => { <i32>::sse_encode(17, serializer); <String>::sse_encode(message, serializer);
+ }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseEncode for crate::StopSharingResult {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.transfer_id, serializer);
+<u64>::sse_encode(self.connected_receivers, serializer);}
+                }
+                
+                impl SseEncode for crate::TicketFormat {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::TicketFormat::Raw => { 0 }
+crate::TicketFormat::HashSeq => { 1 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::TicketInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.node_id, serializer);
+<Option<String>>::sse_encode(self.relay_url, serializer);
+<Vec<String>>::sse_encode(self.direct_addrs, serializer);
+<String>::sse_encode(self.hash, serializer);
+<crate::TicketFormat>::sse_encode(self.format, serializer);
+<bool>::sse_encode(self.is_collection, serializer);
+<Option<String>>::sse_encode(self.file_name, serializer);}
+                }
+                
+                impl SseEncode for crate::TicketWarning {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::TicketWarning::DirectOnly => { 0 }
+crate::TicketWarning::RelayOnly => { 1 }
+crate::TicketWarning::NoAddresses => { 2 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::TransferFile {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.name, serializer);
+<u64>::sse_encode(self.size, serializer);}
+                }
+                
+                impl SseEncode for crate::TransferInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u64>::sse_encode(self.id, serializer);
+<crate::TransferKind>::sse_encode(self.kind, serializer);
+<crate::TransferState>::sse_encode(self.state, serializer);
+<Option<String>>::sse_encode(self.ticket, serializer);
+<Option<String>>::sse_encode(self.error, serializer);
+<Vec<crate::TransferFile>>::sse_encode(self.files, serializer);
+<Option<String>>::sse_encode(self.short_code, serializer);}
+                }
+                
+                impl SseEncode for crate::TransferKind {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::TransferKind::Send => { 0 }
+crate::TransferKind::Receive => { 1 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::TransferState {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::TransferState::Importing => { 0 }
+crate::TransferState::Waiting => { 1 }
+crate::TransferState::Connected => { 2 }
+crate::TransferState::Transferring => { 3 }
+crate::TransferState::Exporting => { 4 }
+crate::TransferState::Done => { 5 }
+crate::TransferState::Failed => { 6 }
+crate::TransferState::Cancelled => { 7 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for u16 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u16::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for u32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u32::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for u64 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u64::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for u8 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u8(self).unwrap();}
+                }
+                
+                impl SseEncode for () {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+                }
+                
+
+
+        
+        
+        #[cfg(not(target_family = "wasm"))]
+        mod io {
+            // This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+
+
+// Section: imports
+
+use flutter_rust_bridge::{Handler, IntoIntoDart};
+use flutter_rust_bridge::for_generated::{Lockable, transform_result_dco, Lifetimeable};
+use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, WriteBytesExt, ReadBytesExt};use super::*;
+
+// Section: boilerplate
+
+flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+
+        }
+        #[cfg(not(target_family = "wasm"))]
+        pub use io::*;
+        
+        
+        /// cbindgen:ignore
+        #[cfg(target_family = "wasm")]
+        mod web {
+            // This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+
+
+// Section: imports
+
+use flutter_rust_bridge::{Handler, IntoIntoDart};
+use flutter_rust_bridge::for_generated::{Lockable, transform_result_dco, Lifetimeable};
+use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, WriteBytesExt, ReadBytesExt};use super::*;
+                use flutter_rust_bridge::for_generated::wasm_bindgen;
+                use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
+
+
+// Section: boilerplate
+
+flutter_rust_bridge::frb_generated_boilerplate_web!();
+
+
+        }
+        #[cfg(target_family = "wasm")]
+        pub use web::*;
+        
\ No newline at end of file
@@ -0,0 +1,225 @@
+use crate::{error::SendmeError, SENDME_STATE};
+use data_encoding::HEXLOWER;
+use iroh::SecretKey;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+// File inside the config directory that holds the secret key as hex
+const IDENTITY_FILE: &str = "identity.key";
+
+// The key this app instance uses for every endpoint, and where it is stored.
+// Without a path the key only lives for the current process.
+pub struct Identity {
+    pub secret_key: SecretKey,
+    pub path: Option<PathBuf>,
+}
+
+// Loads the identity from `config_dir`, creating it on first use, and returns
+// the node ID
+pub async fn init_identity(config_dir: String) -> anyhow::Result<String> {
+    let dir = PathBuf::from(config_dir);
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| SendmeError::Io {
+            path: Some(dir.display().to_string()),
+            message: e.to_string(),
+        })?;
+    let path = dir.join(IDENTITY_FILE);
+
+    let secret_key = match tokio::fs::read_to_string(&path).await {
+        Ok(secret) => {
+            restrict_permissions(&path).await?;
+            parse_secret(&secret)?
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let secret_key = SecretKey::generate(&mut rand::rng());
+            write_secret(&path, &secret_key).await?;
+            secret_key
+        }
+        Err(e) => {
+            return Err(SendmeError::Io {
+                path: Some(path.display().to_string()),
+                message: e.to_string(),
+            }
+            .into())
+        }
+    };
+
+    let node_id = secret_key.public().to_string();
+    SENDME_STATE.set_identity(Identity {
+        secret_key,
+        path: Some(path),
+    });
+    Ok(node_id)
+}
+
+// The key the node is started with. Before `init_identity` a key is
+// generated once per process, so at least restarts of the node in this run
+// keep the same node ID.
+pub fn secret_key() -> SecretKey {
+    let mut identity = SENDME_STATE.identity.lock().unwrap();
+    identity
+        .get_or_insert_with(|| Identity {
+            secret_key: SecretKey::generate(&mut rand::rng()),
+            path: None,
+        })
+        .secret_key
+        .clone()
+}
+
+pub fn node_id() -> String {
+    secret_key().public().to_string()
+}
+
+// Replaces the identity with a fresh key. A running node keeps the key it was
+// started with until `init_node` is called again.
+pub async fn rotate_identity() -> anyhow::Result<String> {
+    let secret_key = SecretKey::generate(&mut rand::rng());
+    replace_identity(secret_key).await
+}
+
+// The secret key as hex, for backups. Whoever has it can act as this node.
+pub fn export_identity() -> String {
+    HEXLOWER.encode(&secret_key().to_bytes())
+}
+
+// Restores an identity from `export_identity` and returns its node ID
+pub async fn import_identity(secret: String) -> anyhow::Result<String> {
+    replace_identity(parse_secret(&secret)?).await
+}
+
+async fn replace_identity(secret_key: SecretKey) -> anyhow::Result<String> {
+    let path = SENDME_STATE
+        .identity
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|identity| identity.path.clone());
+    if let Some(path) = &path {
+        write_secret(path, &secret_key).await?;
+    }
+    let node_id = secret_key.public().to_string();
+    SENDME_STATE.set_identity(Identity { secret_key, path });
+    Ok(node_id)
+}
+
+fn parse_secret(secret: &str) -> anyhow::Result<SecretKey> {
+    SecretKey::from_str(secret.trim()).map_err(|e| {
+        SendmeError::InvalidIdentity {
+            reason: e.to_string(),
+        }
+        .into()
+    })
+}
+
+// Writes the key next to its final place first, so a crash never leaves a
+// truncated identity behind
+async fn write_secret(path: &Path, secret_key: &SecretKey) -> anyhow::Result<()> {
+    let tmp = path.with_extension("key.tmp");
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let io_error = |e: std::io::Error| SendmeError::Io {
+        path: Some(path.display().to_string()),
+        message: e.to_string(),
+    };
+
+    // The mode only applies to new files, so a file left behind by a crash
+    // must not be reused with whatever permissions it has
+    match tokio::fs::remove_file(&tmp).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(io_error(e).into()),
+        _ => {}
+    }
+    let mut file = options.open(&tmp).await.map_err(io_error)?;
+    let secret = HEXLOWER.encode(&secret_key.to_bytes());
+    tokio::io::AsyncWriteExt::write_all(&mut file, secret.as_bytes())
+        .await
+        .map_err(io_error)?;
+    file.sync_all().await.map_err(io_error)?;
+    drop(file);
+    tokio::fs::rename(&tmp, path).await.map_err(io_error)?;
+    Ok(())
+}
+
+// Only the app itself may read the key
+async fn restrict_permissions(path: &Path) -> anyhow::Result<()> {
+    #[cfg(unix)]
+    {
+        use anyhow::Context;
+        use std::os::unix::fs::PermissionsExt;
+
+        let metadata = tokio::fs::metadata(path).await?;
+        if metadata.permissions().mode() & 0o077 != 0 {
+            tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+                .await
+                .with_context(|| format!("restrict permissions of {}", path.display()))?;
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn mode(path: &Path) -> u32 {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path).unwrap().permissions().mode() & 0o777
+    }
+
+    // The identity is global, so everything that goes through it is one test
+    #[tokio::test]
+    async fn identity_persists_and_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_dir = dir.path().to_string_lossy().to_string();
+        let path = dir.path().join(IDENTITY_FILE);
+
+        let node_id = init_identity(config_dir.clone()).await.unwrap();
+        assert_eq!(init_identity(config_dir.clone()).await.unwrap(), node_id);
+        #[cfg(unix)]
+        assert_eq!(mode(&path), 0o600);
+
+        let secret = export_identity();
+        let rotated = rotate_identity().await.unwrap();
+        assert_ne!(rotated, node_id);
+        assert_eq!(init_identity(config_dir.clone()).await.unwrap(), rotated);
+
+        assert_eq!(import_identity(secret).await.unwrap(), node_id);
+        assert_eq!(init_identity(config_dir).await.unwrap(), node_id);
+        #[cfg(unix)]
+        assert_eq!(mode(&path), 0o600);
+
+        assert!(matches!(
+            import_identity("not a key".to_string())
+                .await
+                .unwrap_err()
+                .downcast_ref::<SendmeError>(),
+            Some(SendmeError::InvalidIdentity { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn leftover_tmp_file_does_not_keep_its_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(IDENTITY_FILE);
+        let tmp = path.with_extension("key.tmp");
+        std::fs::write(&tmp, "leftover").unwrap();
+        std::fs::set_permissions(&tmp, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let secret_key = SecretKey::generate(&mut rand::rng());
+        write_secret(&path, &secret_key).await.unwrap();
+        assert_eq!(mode(&path), 0o600);
+        assert!(!tmp.exists());
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(parse_secret(&written).unwrap().public(), secret_key.public());
+    }
+}
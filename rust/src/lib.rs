@@ -1,21 +1,136 @@
 pub mod api;
+pub mod entry_names;
+pub mod error;
+pub mod identity;
+pub mod node;
+pub mod progress_messages;
+pub mod qr;
+pub mod rate;
 pub mod sendme_core;
+pub mod short_code;
 mod frb_generated;
 
+use crate::frb_generated::StreamSink;
+use crate::identity::Identity;
+use crate::node::Node;
+use crate::rate::RateTracker;
+use crate::short_code::{CodeResolver, InProcessResolver};
+use iroh_blobs::api::TempTag;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use tokio::sync::mpsc;
 use std::collections::HashMap;
+use tokio_util::sync::CancellationToken;
 
-pub type ProgressSender = Arc<Mutex<Option<mpsc::UnboundedSender<ProgressInfo>>>>;
+// Identifies a single send or receive for the lifetime of the app
+pub type TransferId = u64;
 
+// How packets to the remote node currently travel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ConnectionPath {
+    None,
+    Direct,
+    Relay,
+    Mixed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConnectionInfo {
+    pub remote_node_id: String,
+    pub path: ConnectionPath,
+    pub direct_addr: Option<String>,
+    pub relay_url: Option<String>,
+    pub rtt_ms: Option<u64>,
+}
+
+// `stage` together with the file and byte counters describes the update in
+// a language neutral way; `message` is the same rendered for the locale
+// chosen with `api::sendme::set_locale`.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ProgressInfo {
+    pub transfer_id: TransferId,
     pub operation: ProgressOperation,
+    pub stage: ProgressStage,
     pub current: u64,
     pub total: u64,
+    pub file_count: u64,
+    pub files_done: u64,
+    pub file_name: Option<String>,
+    // Bytes of the current file, 0 where it does not apply
+    pub file_current: u64,
+    pub file_total: u64,
+    // Rolling rate, ETA and elapsed time of byte based operations, 0 and
+    // None where they do not apply
+    pub bytes_per_second: u64,
+    pub eta_secs: Option<u64>,
+    pub elapsed_ms: u64,
+    // Set on receive updates once connected to the sender
+    pub connection: Option<ConnectionInfo>,
     pub message: String,
 }
 
+impl ProgressInfo {
+    pub fn new(
+        transfer_id: TransferId,
+        operation: ProgressOperation,
+        stage: ProgressStage,
+        current: u64,
+        total: u64,
+    ) -> Self {
+        Self {
+            transfer_id,
+            operation,
+            stage,
+            current,
+            total,
+            file_count: 0,
+            files_done: 0,
+            file_name: None,
+            file_current: 0,
+            file_total: 0,
+            bytes_per_second: 0,
+            eta_secs: None,
+            elapsed_ms: 0,
+            connection: None,
+            message: String::new(),
+        }
+    }
+
+    pub fn with_file_count(mut self, file_count: u64) -> Self {
+        self.file_count = file_count;
+        self
+    }
+
+    pub fn with_files_done(mut self, files_done: u64) -> Self {
+        self.files_done = files_done;
+        self
+    }
+
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    pub fn with_file_progress(mut self, file_current: u64, file_total: u64) -> Self {
+        self.file_current = file_current;
+        self.file_total = file_total;
+        self
+    }
+
+    pub fn with_connection(mut self, connection: Option<ConnectionInfo>) -> Self {
+        self.connection = connection;
+        self
+    }
+
+    // Fills in the rate figures, taking the remaining bytes from `current`
+    // and `total`
+    pub fn with_rate(mut self, rate: &RateTracker) -> Self {
+        self.bytes_per_second = rate.bytes_per_second();
+        self.eta_secs = rate.eta_secs(self.total.saturating_sub(self.current));
+        self.elapsed_ms = rate.elapsed().as_millis() as u64;
+        self
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ProgressOperation {
     Import,
@@ -24,72 +139,749 @@ pub enum ProgressOperation {
     Connect,
 }
 
-// Stream-based progress callback for real-time updates
-#[flutter_rust_bridge::frb]
-pub struct ProgressStream {
-    #[frb(ignore)]
-    pub receiver: Option<mpsc::UnboundedReceiver<ProgressInfo>>,
+// What a progress update is about, independent of the UI language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ProgressStage {
+    ImportStarted,
+    ImportingFile,
+    FileImported,
+    ImportFinished,
+    WaitingForReceiver,
+    ParsingTicket,
+    Connecting,
+    FetchingInfo,
+    ConnectionChanged,
+    DownloadStarted,
+    DownloadResumed,
+    Downloading,
+    DownloadFinished,
+    ExportingFile,
+    ExportFinished,
+    ReceiveFinished,
 }
 
-impl ProgressStream {
-    pub fn new() -> (Self, ProgressSender) {
-        let (sender, receiver) = mpsc::unbounded_channel();
-        let stream = ProgressStream {
-            receiver: Some(receiver),
-        };
-        let sender = Arc::new(Mutex::new(Some(sender)));
-        (stream, sender)
+// Language of `ProgressInfo::message`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MessageLocale {
+    #[default]
+    Zh,
+    En,
+}
+
+// Pushes progress updates of one transfer to the Dart stream registered
+// through `api::sendme::progress_stream`. Sending is a no-op while nobody
+// listens.
+#[derive(Clone)]
+pub struct ProgressSender {
+    transfer_id: TransferId,
+}
+
+impl ProgressSender {
+    pub fn new(transfer_id: TransferId) -> Self {
+        Self { transfer_id }
+    }
+
+    pub fn transfer_id(&self) -> TransferId {
+        self.transfer_id
     }
 
-    pub fn try_recv(&mut self) -> Option<ProgressInfo> {
-        if let Some(ref mut receiver) = self.receiver {
-            receiver.try_recv().ok()
-        } else {
-            None
+    pub fn send(&self, info: ProgressInfo) {
+        SENDME_STATE.send_progress(info);
+    }
+}
+
+// What a receiver did with a collection we are sharing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SendEventKind {
+    ClientConnected,
+    RequestReceived,
+    Progress,
+    Completed,
+    Aborted,
+    ClientDisconnected,
+}
+
+// Sender side counterpart of `ProgressInfo`. One connection can carry several
+// requests; `bytes_sent` and `total` are per request.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SendEvent {
+    pub transfer_id: TransferId,
+    pub kind: SendEventKind,
+    pub connection_id: u64,
+    pub request_id: Option<u64>,
+    pub node_id: Option<String>,
+    pub bytes_sent: u64,
+    pub total: u64,
+    pub bytes_per_second: u64,
+    pub eta_secs: Option<u64>,
+    pub elapsed_ms: u64,
+}
+
+impl SendEvent {
+    pub fn set_rate(&mut self, rate: &RateTracker) {
+        self.bytes_per_second = rate.bytes_per_second();
+        self.eta_secs = rate.eta_secs(self.total.saturating_sub(self.bytes_sent));
+        self.elapsed_ms = rate.elapsed().as_millis() as u64;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TransferKind {
+    Send,
+    Receive,
+}
+
+// Sends move between Waiting, Connected and Transferring for as long as they
+// are shared, depending on what receivers do, and are Done once they stop
+// sharing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TransferState {
+    Importing,
+    Waiting,
+    Connected,
+    Transferring,
+    Exporting,
+    Done,
+    Failed,
+    Cancelled,
+}
+
+impl TransferState {
+    pub fn is_finished(self) -> bool {
+        matches!(
+            self,
+            TransferState::Done | TransferState::Failed | TransferState::Cancelled
+        )
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TransferFile {
+    pub name: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TransferInfo {
+    pub id: TransferId,
+    pub kind: TransferKind,
+    pub state: TransferState,
+    pub ticket: Option<String>,
+    pub error: Option<String>,
+    // Known once a receive has the collection, before export starts
+    pub files: Vec<TransferFile>,
+    // Set on sends once `api::short_code::publish_short_code` was called
+    pub short_code: Option<String>,
+}
+
+// Which relay servers an endpoint uses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RelayModeOption {
+    // The public relays run by n0
+    #[default]
+    Default,
+    // Only the servers listed in `EndpointConfig::relay_urls`
+    Custom,
+    Disabled,
+}
+
+// How endpoints connect to and find peers. The default matches iroh's own
+// defaults: n0 relays, DNS discovery and pkarr publishing, any port.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EndpointConfig {
+    pub relay_mode: RelayModeOption,
+    pub relay_urls: Vec<String>,
+    pub dns_discovery: bool,
+    pub pkarr_publishing: bool,
+    // Needs the `discovery-local-network` feature
+    pub mdns_discovery: bool,
+    // IPv4 or IPv6 address to bind to, all interfaces if unset
+    pub bind_addr: Option<String>,
+    pub bind_port: Option<u16>,
+    // Offline transfers on the same network: relays and internet discovery
+    // are off, mDNS is on and tickets only carry direct addresses. Overrides
+    // the relay and discovery fields above.
+    pub lan_only: bool,
+    // How long a send waits for the relay connection and local addresses
+    // before it hands out a ticket with whatever it has
+    pub ready_timeout_ms: u64,
+}
+
+impl Default for EndpointConfig {
+    fn default() -> Self {
+        Self {
+            relay_mode: RelayModeOption::Default,
+            relay_urls: Vec::new(),
+            dns_discovery: true,
+            pkarr_publishing: true,
+            mdns_discovery: false,
+            bind_addr: None,
+            bind_port: None,
+            lan_only: false,
+            // Long enough for iroh's first network report
+            ready_timeout_ms: 5000,
+        }
+    }
+}
+
+impl EndpointConfig {
+    pub fn lan_only() -> Self {
+        Self {
+            lan_only: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn relays_enabled(&self) -> bool {
+        !self.lan_only && self.relay_mode != RelayModeOption::Disabled
+    }
+}
+
+// Where short codes are published and looked up
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub enum CodeResolverConfig {
+    // Only within this app instance
+    #[default]
+    InProcess,
+    // A rendezvous server, see `short_code::HttpResolver`
+    Http { base_url: String },
+}
+
+// How much of a QR code may be damaged or covered before it stops scanning
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum QrEcLevel {
+    L,
+    #[default]
+    M,
+    Q,
+    H,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum QrFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct QrOptions {
+    pub format: QrFormat,
+    pub ec_level: QrEcLevel,
+    // Pixels per module for PNG, user units for SVG
+    pub module_size: u32,
+}
+
+impl Default for QrOptions {
+    fn default() -> Self {
+        Self {
+            format: QrFormat::Png,
+            ec_level: QrEcLevel::M,
+            module_size: 8,
         }
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum QrImage {
+    Png { bytes: Vec<u8> },
+    Svg { markup: String },
+}
+
+// How a send is shared and what it produces besides the ticket
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct SendOptions {
+    // Share a single file as a raw blob instead of a collection. The ticket
+    // is shorter; the file name is appended to it as `#<percent-encoded name>`.
+    pub raw: bool,
+    // Render the ticket as a QR code into `SendResult::qr`
+    pub qr: Option<QrOptions>,
+}
+
+// Where the node keeps the blobs it shares and how its endpoint reaches
+// peers. Unset directories fall back to the current working directory, which
+// is not writable on mobile platforms.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct NodeOptions {
+    pub store_dir: Option<String>,
+    pub endpoint: EndpointConfig,
+}
+
+// What to do when a received file would overwrite an existing one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ConflictPolicy {
+    #[default]
+    Fail,
+    Overwrite,
+    Skip,
+    RenameWithSuffix,
+    SkipIfIdenticalHash,
+}
+
+// Where a receive exports files to and keeps its blob store while
+// downloading. Unset directories fall back to the current working directory.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ReceiveOptions {
+    pub output_dir: Option<String>,
+    pub temp_dir: Option<String>,
+    pub conflict_policy: ConflictPolicy,
+    // Name for the file of a raw blob ticket. Overrides the name carried by
+    // the ticket; the hash is used if neither is set.
+    pub raw_file_name: Option<String>,
+}
+
+// Why some receivers may not be able to use a ticket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TicketWarning {
+    // No relay, only receivers that can reach one of the addresses directly
+    DirectOnly,
+    // No direct address, all data goes through the relay
+    RelayOnly,
+    // Neither, receivers have to find the sender through discovery
+    NoAddresses,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SendResult {
+    pub transfer_id: TransferId,
     pub ticket: String,
     pub hash: String,
     pub size: u64,
     pub file_count: u64,
+    pub warning: Option<TicketWarning>,
+    pub qr: Option<QrImage>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ReceiveResult {
+    pub transfer_id: TransferId,
     pub file_count: u64,
     pub size: u64,
     pub duration_ms: u64,
+    // Download throughput, 0 if everything was already local
+    pub average_bytes_per_second: u64,
+    pub peak_bytes_per_second: u64,
+    // How the sender was reached at the end of the download
+    pub connection: Option<ConnectionInfo>,
+}
+
+// Mirrors `iroh_blobs::BlobFormat`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TicketFormat {
+    Raw,
+    HashSeq,
+}
+
+// The contents of a ticket, as returned by `api::sendme::parse_ticket`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TicketInfo {
+    pub node_id: String,
+    pub relay_url: Option<String>,
+    pub direct_addrs: Vec<String>,
+    pub hash: String,
+    pub format: TicketFormat,
+    // Whether the hash is a collection of files rather than a single blob
+    pub is_collection: bool,
+    // Name of the file behind a raw ticket, if the sender included it
+    pub file_name: Option<String>,
 }
 
-// Simplified global state to keep senders alive
-use std::any::Any;
+// A receive that failed or was cancelled and left its store behind
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PartialDownload {
+    pub hash: String,
+    pub ticket: Option<String>,
+    pub local_bytes: u64,
+    pub path: String,
+}
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StopSharingResult {
+    pub transfer_id: TransferId,
+    pub connected_receivers: u64,
+}
+
+// Keeps a shared collection in the node's store. The node itself only
+// serves it while it is registered there as well.
+pub struct SendHandle {
+    pub temp_tag: TempTag,
+    pub connected_receivers: Arc<AtomicUsize>,
+}
+
+pub struct TransferHandle {
+    pub info: TransferInfo,
+    pub send: Option<SendHandle>,
+    pub cancel: CancellationToken,
+    // Whether a cancelled receive keeps its partial store around
+    pub keep_partial: bool,
+    // Whether `receive_file` has taken on a receive made by `create_receive`
+    pub started: bool,
+}
+
+// Finished transfers kept for `get_transfer` before the oldest are dropped
+const MAX_FINISHED_TRANSFERS: usize = 100;
+
+// Registry of all transfers started by this app instance
+#[derive(Default)]
 pub struct SendmeState {
-    pub senders: Arc<Mutex<HashMap<String, Box<dyn Any + Send + Sync>>>>,
+    pub transfers: Arc<Mutex<HashMap<TransferId, TransferHandle>>>,
+    pub progress_sink: Arc<Mutex<Option<StreamSink<ProgressInfo>>>>,
+    pub send_event_sink: Arc<Mutex<Option<StreamSink<SendEvent>>>>,
+    pub locale: Mutex<MessageLocale>,
+    pub identity: Mutex<Option<Identity>>,
+    pub node: Mutex<Option<Node>>,
+    pub code_resolver: Mutex<Option<Arc<dyn CodeResolver>>>,
+    next_transfer_id: AtomicU64,
 }
 
 impl SendmeState {
     pub fn new() -> Self {
-        Self {
-            senders: Arc::new(Mutex::new(HashMap::new())),
+        Self::default()
+    }
+
+    pub fn start_transfer(&self, kind: TransferKind) -> TransferId {
+        let id = self.next_transfer_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let state = match kind {
+            TransferKind::Send => TransferState::Importing,
+            TransferKind::Receive => TransferState::Waiting,
+        };
+        let info = TransferInfo {
+            id,
+            kind,
+            state,
+            ticket: None,
+            error: None,
+            files: Vec::new(),
+            short_code: None,
+        };
+        let mut transfers = self.transfers.lock().unwrap();
+        prune_finished(&mut transfers);
+        let handle = TransferHandle {
+            info,
+            send: None,
+            cancel: CancellationToken::new(),
+            keep_partial: false,
+            started: false,
+        };
+        transfers.insert(id, handle);
+        id
+    }
+
+    // Marks a receive as started, so the same ID cannot be used by two calls.
+    // Returns the state of receives that are unknown or already started.
+    pub fn begin_receive(&self, id: TransferId) -> Result<(), Option<TransferState>> {
+        let mut transfers = self.transfers.lock().unwrap();
+        match transfers.get_mut(&id) {
+            Some(transfer)
+                if transfer.info.kind == TransferKind::Receive
+                    && !transfer.started
+                    && !transfer.info.state.is_finished() =>
+            {
+                transfer.started = true;
+                Ok(())
+            }
+            Some(transfer) => Err(Some(transfer.info.state)),
+            None => Err(None),
         }
     }
 
-    pub fn add_sender(&self, ticket: String, sender: Box<dyn Any + Send + Sync>) {
-        let mut senders = self.senders.lock().unwrap();
-        senders.insert(ticket, sender);
+    // Whether a receive is still waiting for `receive_file`
+    pub fn receive_pending(&self, id: TransferId) -> bool {
+        let transfers = self.transfers.lock().unwrap();
+        transfers.get(&id).is_some_and(|transfer| {
+            transfer.info.kind == TransferKind::Receive && !transfer.started
+        })
     }
 
-    pub fn remove_sender(&self, ticket: &str) {
-        let mut senders = self.senders.lock().unwrap();
-        senders.remove(ticket);
+    pub fn set_transfer_ticket(&self, id: TransferId, ticket: String) {
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(&id) {
+            transfer.info.ticket = Some(ticket);
+        }
+    }
+
+    pub fn cancel_token(&self, id: TransferId) -> CancellationToken {
+        let transfers = self.transfers.lock().unwrap();
+        transfers
+            .get(&id)
+            .map(|transfer| transfer.cancel.clone())
+            .unwrap_or_default()
+    }
+
+    // Asks a running transfer to stop. Returns false if there is nothing left
+    // to cancel.
+    pub fn cancel_transfer(&self, id: TransferId, keep_partial: bool) -> bool {
+        let mut transfers = self.transfers.lock().unwrap();
+        match transfers.get_mut(&id) {
+            Some(transfer) if !transfer.info.state.is_finished() => {
+                transfer.keep_partial = keep_partial;
+                transfer.cancel.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn keep_partial(&self, id: TransferId) -> bool {
+        let transfers = self.transfers.lock().unwrap();
+        transfers.get(&id).is_some_and(|transfer| transfer.keep_partial)
+    }
+
+    pub fn set_transfer_state(&self, id: TransferId, state: TransferState) {
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(&id) {
+            transfer.info.state = state;
+        }
+    }
+
+    // Moves a transfer that has not finished yet to the state returned by
+    // `state`, which is evaluated under the registry lock so concurrent
+    // updates cannot leave a stale state behind
+    pub fn update_active_state(&self, id: TransferId, state: impl FnOnce() -> TransferState) {
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(&id) {
+            if !transfer.info.state.is_finished() {
+                transfer.info.state = state();
+            }
+        }
+    }
+
+    pub fn fail_transfer(&self, id: TransferId, error: &anyhow::Error) {
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(&id) {
+            transfer.info.state = TransferState::Failed;
+            transfer.info.error = Some(format!("{error:#}"));
+        }
+    }
+
+    pub fn set_transfer_files(&self, id: TransferId, files: Vec<TransferFile>) {
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(&id) {
+            transfer.info.files = files;
+        }
+    }
+
+    // Keeps the provider of a send alive and makes it discoverable by ticket
+    pub fn set_send_handle(&self, id: TransferId, ticket: String, handle: SendHandle) {
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(&id) {
+            transfer.info.ticket = Some(ticket);
+            transfer.send = Some(handle);
+        }
+    }
+
+    // Removes the provider of a send so it can be shut down. Accepts either
+    // the transfer ID or the ticket handed out by `send_file`.
+    pub fn take_send_handle(&self, ticket_or_id: &str) -> Option<(TransferId, SendHandle)> {
+        let mut transfers = self.transfers.lock().unwrap();
+        let id = match ticket_or_id.parse::<TransferId>() {
+            Ok(id) => id,
+            Err(_) => transfers
+                .values()
+                .find(|transfer| transfer.info.ticket.as_deref() == Some(ticket_or_id))
+                .map(|transfer| transfer.info.id)?,
+        };
+        let handle = transfers.get_mut(&id)?.send.take()?;
+        Some((id, handle))
+    }
+
+    pub fn transfer(&self, id: TransferId) -> Option<TransferInfo> {
+        let transfers = self.transfers.lock().unwrap();
+        transfers.get(&id).map(|transfer| transfer.info.clone())
+    }
+
+    // Removes a finished transfer. Returns false if it is unknown or still
+    // running.
+    pub fn forget_transfer(&self, id: TransferId) -> bool {
+        let mut transfers = self.transfers.lock().unwrap();
+        match transfers.get(&id) {
+            Some(transfer) if transfer.info.state.is_finished() => {
+                transfers.remove(&id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn active_transfers(&self) -> Vec<TransferInfo> {
+        let transfers = self.transfers.lock().unwrap();
+        let mut active = transfers
+            .values()
+            .filter(|transfer| !transfer.info.state.is_finished())
+            .map(|transfer| transfer.info.clone())
+            .collect::<Vec<_>>();
+        active.sort_by_key(|info| info.id);
+        active
+    }
+
+    // Replaces the current progress listener; the previous Dart stream is
+    // closed when its sink is dropped.
+    pub fn set_progress_sink(&self, sink: StreamSink<ProgressInfo>) {
+        let mut progress_sink = self.progress_sink.lock().unwrap();
+        *progress_sink = Some(sink);
+    }
+
+    // Replaces the current sender event listener, like `set_progress_sink`
+    pub fn set_send_event_sink(&self, sink: StreamSink<SendEvent>) {
+        let mut send_event_sink = self.send_event_sink.lock().unwrap();
+        *send_event_sink = Some(sink);
+    }
+
+    // Events of a send are reported for as long as it is shared, so the
+    // current listener is looked up every time
+    pub fn send_event(&self, event: SendEvent) {
+        if let Some(sink) = self.send_event_sink.lock().unwrap().as_ref() {
+            let _ = sink.add(event);
+        }
+    }
+
+    pub fn set_identity(&self, identity: Identity) {
+        *self.identity.lock().unwrap() = Some(identity);
+    }
+
+    pub fn node(&self) -> Option<Node> {
+        self.node.lock().unwrap().clone()
+    }
+
+    // Returns the node that was running before, if any
+    pub fn set_node(&self, node: Node) -> Option<Node> {
+        self.node.lock().unwrap().replace(node)
+    }
+
+    pub fn take_node(&self) -> Option<Node> {
+        self.node.lock().unwrap().take()
+    }
+
+    // Falls back to codes that only resolve within this app instance
+    pub fn code_resolver(&self) -> Arc<dyn CodeResolver> {
+        self.code_resolver
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| Arc::new(InProcessResolver::default()))
+            .clone()
+    }
+
+    pub fn set_code_resolver(&self, resolver: Arc<dyn CodeResolver>) {
+        *self.code_resolver.lock().unwrap() = Some(resolver);
+    }
+
+    pub fn set_short_code(&self, id: TransferId, code: String) {
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(&id) {
+            transfer.info.short_code = Some(code);
+        }
+    }
+
+    pub fn set_locale(&self, locale: MessageLocale) {
+        *self.locale.lock().unwrap() = locale;
+    }
+
+    pub fn progress_sender(&self, transfer_id: TransferId) -> ProgressSender {
+        ProgressSender::new(transfer_id)
+    }
+
+    // Transfers can outlive a listener and start before there is one, so the
+    // current listener and locale are looked up for every update
+    pub fn send_progress(&self, mut info: ProgressInfo) {
+        let locale = *self.locale.lock().unwrap();
+        if let Some(sink) = self.progress_sink.lock().unwrap().as_ref() {
+            info.message = progress_messages::render(locale, &info);
+            // The Dart listener may already be gone; progress is best effort
+            // and must never fail a transfer.
+            let _ = sink.add(info);
+        }
+    }
+}
+
+// Drops the oldest finished transfers beyond `MAX_FINISHED_TRANSFERS`
+fn prune_finished(transfers: &mut HashMap<TransferId, TransferHandle>) {
+    let mut finished = transfers
+        .values()
+        .filter(|transfer| transfer.info.state.is_finished())
+        .map(|transfer| transfer.info.id)
+        .collect::<Vec<_>>();
+    if finished.len() <= MAX_FINISHED_TRANSFERS {
+        return;
+    }
+    finished.sort_unstable();
+    for id in &finished[..finished.len() - MAX_FINISHED_TRANSFERS] {
+        transfers.remove(id);
     }
 }
 
 lazy_static::lazy_static! {
     pub static ref SENDME_STATE: SendmeState = SendmeState::new();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer_ids_are_unique() {
+        let state = SendmeState::new();
+        let send = state.start_transfer(TransferKind::Send);
+        let receive = state.start_transfer(TransferKind::Receive);
+        assert_ne!(send, receive);
+        assert_eq!(state.transfer(send).unwrap().state, TransferState::Importing);
+        assert_eq!(state.transfer(receive).unwrap().state, TransferState::Waiting);
+    }
+
+    #[test]
+    fn receives_begin_once() {
+        let state = SendmeState::new();
+        let receive = state.start_transfer(TransferKind::Receive);
+        assert!(state.receive_pending(receive));
+        assert_eq!(state.begin_receive(receive), Ok(()));
+        assert!(!state.receive_pending(receive));
+        assert_eq!(state.begin_receive(receive), Err(Some(TransferState::Waiting)));
+
+        let send = state.start_transfer(TransferKind::Send);
+        assert_eq!(state.begin_receive(send), Err(Some(TransferState::Importing)));
+        assert_eq!(state.begin_receive(send + 1), Err(None));
+
+        // A receive cancelled before `receive_file` cannot be started anymore
+        let cancelled = state.start_transfer(TransferKind::Receive);
+        state.set_transfer_state(cancelled, TransferState::Cancelled);
+        assert_eq!(state.begin_receive(cancelled), Err(Some(TransferState::Cancelled)));
+    }
+
+    #[test]
+    fn finished_states_are_final() {
+        let state = SendmeState::new();
+        let id = state.start_transfer(TransferKind::Receive);
+        state.update_active_state(id, || TransferState::Transferring);
+        assert_eq!(state.transfer(id).unwrap().state, TransferState::Transferring);
+        assert!(!state.forget_transfer(id));
+
+        state.set_transfer_state(id, TransferState::Done);
+        state.update_active_state(id, || TransferState::Exporting);
+        assert_eq!(state.transfer(id).unwrap().state, TransferState::Done);
+        assert!(!state.cancel_transfer(id, false));
+
+        assert!(state.forget_transfer(id));
+        assert!(state.transfer(id).is_none());
+        assert!(!state.forget_transfer(id));
+    }
+
+    #[test]
+    fn finished_transfers_are_listed_and_pruned() {
+        let state = SendmeState::new();
+        let finished = (0..MAX_FINISHED_TRANSFERS + 1)
+            .map(|_| {
+                let id = state.start_transfer(TransferKind::Send);
+                state.set_transfer_state(id, TransferState::Done);
+                id
+            })
+            .collect::<Vec<_>>();
+        let running = state.start_transfer(TransferKind::Receive);
+
+        let active = state.active_transfers();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, running);
+
+        // Only the oldest finished transfer is dropped
+        assert!(state.transfer(finished[0]).is_none());
+        assert!(finished[1..].iter().all(|id| state.transfer(*id).is_some()));
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{
+    error::SendmeError,
+    identity,
+    rate::RateTracker,
+    sendme_core::{bind_endpoint, stop_sharing, writable_dir, PROGRESS_INTERVAL},
+    EndpointConfig, NodeOptions, SendEvent, SendEventKind, TransferId, TransferKind, TransferState,
+    SENDME_STATE,
+};
+use iroh::{protocol::Router, Endpoint};
+use iroh_blobs::{
+    provider::events::{
+        AbortReason, ConnectMode, EventMask, EventSender, ProviderMessage, RequestMode,
+        RequestUpdate,
+    },
+    store::{
+        fs::{options::Options, FsStore},
+        GcConfig,
+    },
+    BlobsProtocol, Hash,
+};
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    future::Future,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tokio::task::JoinSet;
+
+// The node's blob store inside `NodeOptions::store_dir`
+const STORE_DIR: &str = ".sendme-node";
+// How often blobs that are no longer shared are removed from the store
+const GC_INTERVAL: Duration = Duration::from_secs(60);
+
+// A collection registered with the node by a send
+pub struct Share {
+    // The collection and all of its blobs, requests for anything else are
+    // rejected
+    pub hashes: HashSet<Hash>,
+    pub total: u64,
+    pub connected_receivers: Arc<AtomicUsize>,
+    // Requests for the share that are being served right now
+    pub requests: Arc<AtomicUsize>,
+    // The tasks following those requests. Dropping the share aborts them,
+    // and the provider resets an upload whose updates are no longer read.
+    pub uploads: JoinSet<()>,
+}
+
+type Shares = Arc<Mutex<HashMap<TransferId, Share>>>;
+
+// The endpoint, router and store shared by all transfers of this app
+// instance. Sends add their collections to the store and register them here,
+// receives dial from the same endpoint.
+#[derive(Clone)]
+pub struct Node {
+    pub router: Router,
+    pub store: FsStore,
+    pub store_dir: PathBuf,
+    pub config: EndpointConfig,
+    shares: Shares,
+}
+
+impl Node {
+    pub fn endpoint(&self) -> &Endpoint {
+        self.router.endpoint()
+    }
+
+    pub fn share(&self, transfer_id: TransferId, share: Share) {
+        self.shares.lock().unwrap().insert(transfer_id, share);
+    }
+
+    // Rejects new requests for the share and cuts off the running ones
+    pub fn unshare(&self, transfer_id: TransferId) -> Option<Share> {
+        self.shares.lock().unwrap().remove(&transfer_id)
+    }
+}
+
+// The running node, for everything that needs the network
+pub fn node() -> anyhow::Result<Node> {
+    SENDME_STATE
+        .node()
+        .ok_or_else(|| SendmeError::NodeNotStarted.into())
+}
+
+// Starts the node and returns its node ID. A node that is already running is
+// shut down first, which is how a new endpoint configuration takes effect.
+pub async fn init_node(options: NodeOptions) -> anyhow::Result<String> {
+    shutdown_node().await?;
+
+    let root = writable_dir(options.store_dir.as_deref(), "store directory").await?;
+    let store_dir = root.join(STORE_DIR);
+    // Shares do not survive a restart, so neither does their data
+    if store_dir.exists() {
+        tokio::fs::remove_dir_all(&store_dir).await?;
+    }
+    tokio::fs::create_dir_all(&store_dir).await?;
+    let mut store_options = Options::new(&store_dir);
+    store_options.gc = Some(GcConfig {
+        interval: GC_INTERVAL,
+        add_protected: None,
+    });
+    let store = FsStore::load_with_opts(store_dir.join("blobs.db"), store_options).await?;
+
+    let endpoint = match bind_endpoint(
+        &options.endpoint,
+        identity::secret_key(),
+        vec![iroh_blobs::ALPN.to_vec()],
+    )
+    .await
+    {
+        Ok(endpoint) => endpoint,
+        Err(e) => {
+            store.shutdown().await?;
+            return Err(e);
+        }
+    };
+
+    let shares = Shares::default();
+    let events = serve_provider_events(shares.clone());
+    let blobs = BlobsProtocol::new(&store, Some(events));
+    let router = Router::builder(endpoint)
+        .accept(iroh_blobs::ALPN, blobs)
+        .spawn();
+    let node_id = router.endpoint().id().to_string();
+
+    let node = Node {
+        router,
+        store,
+        store_dir,
+        config: options.endpoint,
+        shares,
+    };
+    // Two concurrent starts, the later one wins
+    if let Some(previous) = SENDME_STATE.set_node(node) {
+        close(previous).await?;
+    }
+    Ok(node_id)
+}
+
+// Stops every share and closes the endpoint. Running receives fail.
+pub async fn shutdown_node() -> anyhow::Result<()> {
+    for info in SENDME_STATE.active_transfers() {
+        if info.kind == TransferKind::Send {
+            // Sends that are still importing fail once the store is gone
+            let _ = stop_sharing(info.id.to_string()).await;
+        }
+    }
+    match SENDME_STATE.take_node() {
+        Some(node) => close(node).await,
+        None => Ok(()),
+    }
+}
+
+async fn close(node: Node) -> anyhow::Result<()> {
+    // Closing the router also shuts down the store, so the directory can go
+    node.router.shutdown().await?;
+    tokio::fs::remove_dir_all(&node.store_dir).await?;
+    Ok(())
+}
+
+// A receiver connection and the shares it has requested something from
+#[derive(Default)]
+struct Peer {
+    node_id: Option<String>,
+    // Connected receivers and running requests of each share
+    shares: HashMap<TransferId, (Arc<AtomicUsize>, Arc<AtomicUsize>)>,
+}
+
+fn send_event(
+    transfer_id: TransferId,
+    kind: SendEventKind,
+    connection_id: u64,
+    node_id: Option<String>,
+    total: u64,
+) -> SendEvent {
+    SendEvent {
+        transfer_id,
+        kind,
+        connection_id,
+        request_id: None,
+        node_id,
+        bytes_sent: 0,
+        total,
+        bytes_per_second: 0,
+        eta_secs: None,
+        elapsed_ms: 0,
+    }
+}
+
+// What the event loop needs of a share, without holding the lock
+struct ShareRef {
+    transfer_id: TransferId,
+    total: u64,
+    connected: Arc<AtomicUsize>,
+    requests: Arc<AtomicUsize>,
+}
+
+// The share all of `hashes` belong to
+fn find_share(shares: &Shares, hashes: &[Hash]) -> Option<ShareRef> {
+    let shares = shares.lock().unwrap();
+    shares
+        .iter()
+        .find(|(_, share)| hashes.iter().all(|hash| share.hashes.contains(hash)))
+        .map(|(id, share)| ShareRef {
+            transfer_id: *id,
+            total: share.total,
+            connected: share.connected_receivers.clone(),
+            requests: share.requests.clone(),
+        })
+}
+
+// Transferring while requests are served, Connected while receivers are
+// around and Waiting otherwise. Shares that were stopped stay Done.
+fn update_share_state(transfer_id: TransferId, connected: &AtomicUsize, requests: &AtomicUsize) {
+    SENDME_STATE.update_active_state(transfer_id, || {
+        if requests.load(Ordering::Relaxed) > 0 {
+            TransferState::Transferring
+        } else if connected.load(Ordering::Relaxed) > 0 {
+            TransferState::Connected
+        } else {
+            TransferState::Waiting
+        }
+    });
+}
+
+// Only lets receivers fetch what is currently shared and forwards what they
+// do to the sender event stream of the share they ask for. A connection
+// counts as connected to a share from its first request for it.
+fn serve_provider_events(shares: Shares) -> EventSender {
+    let mask = EventMask {
+        connected: ConnectMode::Notify,
+        get: RequestMode::InterceptLog,
+        get_many: RequestMode::InterceptLog,
+        ..EventMask::DEFAULT
+    };
+    let (events, mut rx) = EventSender::channel(32, mask);
+    tokio::spawn(async move {
+        let mut peers: HashMap<u64, Peer> = HashMap::new();
+        while let Some(message) = rx.recv().await {
+            let (connection_id, request_id, hashes, tx, updates) = match message {
+                ProviderMessage::ClientConnectedNotify(msg) => {
+                    let peer = peers.entry(msg.inner.connection_id).or_default();
+                    peer.node_id = msg.inner.endpoint_id.map(|id| id.to_string());
+                    continue;
+                }
+                ProviderMessage::ConnectionClosed(msg) => {
+                    let connection_id = msg.inner.connection_id;
+                    let Some(peer) = peers.remove(&connection_id) else {
+                        continue;
+                    };
+                    for (transfer_id, (connected, requests)) in peer.shares {
+                        let _ = connected.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                            Some(n.saturating_sub(1))
+                        });
+                        update_share_state(transfer_id, &connected, &requests);
+                        SENDME_STATE.send_event(send_event(
+                            transfer_id,
+                            SendEventKind::ClientDisconnected,
+                            connection_id,
+                            peer.node_id.clone(),
+                            0,
+                        ));
+                    }
+                    continue;
+                }
+                ProviderMessage::GetRequestReceived(msg) => (
+                    msg.inner.connection_id,
+                    msg.inner.request_id,
+                    vec![msg.inner.request.hash],
+                    msg.tx,
+                    msg.rx,
+                ),
+                ProviderMessage::GetManyRequestReceived(msg) => (
+                    msg.inner.connection_id,
+                    msg.inner.request_id,
+                    msg.inner.request.hashes,
+                    msg.tx,
+                    msg.rx,
+                ),
+                _ => continue,
+            };
+
+            let Some(share) = find_share(&shares, &hashes) else {
+                tx.send(Err(AbortReason::Permission)).await.ok();
+                continue;
+            };
+            tx.send(Ok(())).await.ok();
+            let ShareRef {
+                transfer_id,
+                total,
+                connected,
+                requests,
+            } = share;
+
+            let peer = peers.entry(connection_id).or_default();
+            if let Entry::Vacant(entry) = peer.shares.entry(transfer_id) {
+                connected.fetch_add(1, Ordering::Relaxed);
+                entry.insert((connected.clone(), requests.clone()));
+                SENDME_STATE.send_event(send_event(
+                    transfer_id,
+                    SendEventKind::ClientConnected,
+                    connection_id,
+                    peer.node_id.clone(),
+                    total,
+                ));
+            }
+            let event = SendEvent {
+                request_id: Some(request_id),
+                ..send_event(
+                    transfer_id,
+                    SendEventKind::RequestReceived,
+                    connection_id,
+                    peer.node_id.clone(),
+                    total,
+                )
+            };
+            requests.fetch_add(1, Ordering::Relaxed);
+            update_share_state(transfer_id, &connected, &requests);
+            let upload = track_request(event, updates, connected, requests);
+            spawn_upload(&shares, transfer_id, upload);
+        }
+    });
+    events
+}
+
+// Runs the task of a request as part of its share. If the share was stopped
+// in the meantime the task is dropped, which aborts the request as well.
+fn spawn_upload(
+    shares: &Shares,
+    transfer_id: TransferId,
+    task: impl Future<Output = ()> + Send + 'static,
+) {
+    let mut shares = shares.lock().unwrap();
+    if let Some(share) = shares.get_mut(&transfer_id) {
+        // Finished tasks stay in the set until they are reaped
+        while share.uploads.try_join_next().is_some() {}
+        share.uploads.spawn(task);
+    }
+}
+
+// Reports the transfer updates of a single request. Blobs are sent one after
+// another, so bytes of finished blobs plus the current offset is the total.
+async fn track_request(
+    mut event: SendEvent,
+    mut updates: irpc::channel::mpsc::Receiver<RequestUpdate>,
+    connected: Arc<AtomicUsize>,
+    requests: Arc<AtomicUsize>,
+) {
+    let transfer_id = event.transfer_id;
+    SENDME_STATE.send_event(event.clone());
+    event.kind = SendEventKind::Progress;
+    let mut finished = 0;
+    let mut current = 0;
+    let mut last_sent = Instant::now();
+    let mut rate = RateTracker::new(0);
+    while let Ok(Some(update)) = updates.recv().await {
+        match update {
+            RequestUpdate::Started(started) => {
+                finished += current;
+                current = 0;
+                event.total = event.total.max(finished + started.size);
+            }
+            RequestUpdate::Progress(progress) => {
+                current = progress.end_offset;
+                rate.record(finished + current);
+                if last_sent.elapsed() >= PROGRESS_INTERVAL {
+                    event.bytes_sent = finished + current;
+                    event.set_rate(&rate);
+                    SENDME_STATE.send_event(event.clone());
+                    last_sent = Instant::now();
+                }
+            }
+            RequestUpdate::Completed(completed) => {
+                event.kind = SendEventKind::Completed;
+                event.bytes_sent = completed.stats.payload_bytes_sent;
+                rate.record(event.bytes_sent);
+                event.set_rate(&rate);
+                // The final event reports the average over the whole request
+                event.bytes_per_second = rate.average();
+                SENDME_STATE.send_event(event);
+                break;
+            }
+            RequestUpdate::Aborted(aborted) => {
+                event.kind = SendEventKind::Aborted;
+                event.bytes_sent = aborted.stats.payload_bytes_sent;
+                event.set_rate(&rate);
+                SENDME_STATE.send_event(event);
+                break;
+            }
+        }
+    }
+    requests.fetch_sub(1, Ordering::Relaxed);
+    update_share_state(transfer_id, &connected, &requests);
+}
@@ -0,0 +1,170 @@
+use crate::{
+    sendme_core::format_bytes, ConnectionInfo, ConnectionPath, MessageLocale, ProgressInfo,
+    ProgressStage,
+};
+
+// Renders a progress update as text for the given locale. Apps that want
+// other languages can ignore `message` and build their own from the stage.
+pub fn render(locale: MessageLocale, info: &ProgressInfo) -> String {
+    match locale {
+        MessageLocale::Zh => render_zh(info),
+        MessageLocale::En => render_en(info),
+    }
+}
+
+fn percent(info: &ProgressInfo) -> f64 {
+    if info.total == 0 {
+        100.0
+    } else {
+        info.current as f64 / info.total as f64 * 100.0
+    }
+}
+
+fn rtt(connection: &ConnectionInfo) -> String {
+    connection
+        .rtt_ms
+        .map(|rtt| format!(", {} ms", rtt))
+        .unwrap_or_default()
+}
+
+fn connection_zh(info: &ProgressInfo) -> String {
+    let Some(connection) = &info.connection else {
+        return "连接已变化".to_string();
+    };
+    let path = match connection.path {
+        ConnectionPath::None => "无可用路径",
+        ConnectionPath::Direct => "直连",
+        ConnectionPath::Relay => "中继",
+        ConnectionPath::Mixed => "直连 + 中继",
+    };
+    format!("连接方式: {}{}", path, rtt(connection))
+}
+
+fn connection_en(info: &ProgressInfo) -> String {
+    let Some(connection) = &info.connection else {
+        return "Connection changed".to_string();
+    };
+    let path = match connection.path {
+        ConnectionPath::None => "no path",
+        ConnectionPath::Direct => "direct",
+        ConnectionPath::Relay => "relayed",
+        ConnectionPath::Mixed => "direct and relayed",
+    };
+    format!("Connection: {}{}", path, rtt(connection))
+}
+
+fn format_duration_zh(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{} 秒", secs),
+        60..=3599 => format!("{} 分 {} 秒", secs / 60, secs % 60),
+        _ => format!("{} 小时 {} 分", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+fn format_duration_en(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {}s", secs / 60, secs % 60),
+        _ => format!("{}h {}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+fn render_zh(info: &ProgressInfo) -> String {
+    let name = info.file_name.as_deref().unwrap_or_default();
+    match info.stage {
+        ProgressStage::ImportStarted => format!("正在导入 {} 个文件", info.file_count),
+        ProgressStage::ImportingFile => format!(
+            "正在导入 {} ({}/{})，{}/s",
+            name,
+            info.files_done,
+            info.file_count,
+            format_bytes(info.bytes_per_second)
+        ),
+        ProgressStage::FileImported => format!(
+            "已导入 {} ({}/{})",
+            name, info.files_done, info.file_count
+        ),
+        ProgressStage::ImportFinished => format!("已处理 {} 个文件", info.file_count),
+        ProgressStage::WaitingForReceiver => "文件导入完成，正在等待接收方连接...".to_string(),
+        ProgressStage::ParsingTicket => "正在解析 ticket...".to_string(),
+        ProgressStage::Connecting => "正在连接到发送方...".to_string(),
+        ProgressStage::FetchingInfo => "已连接，正在获取文件信息...".to_string(),
+        ProgressStage::ConnectionChanged => connection_zh(info),
+        ProgressStage::DownloadStarted => format!(
+            "开始下载 {} 个文件，总大小: {}",
+            info.file_count,
+            format_bytes(info.total)
+        ),
+        ProgressStage::DownloadResumed => format!(
+            "继续下载 {} 个文件，已有 {}/{}",
+            info.file_count,
+            format_bytes(info.current),
+            format_bytes(info.total)
+        ),
+        ProgressStage::Downloading => format!(
+            "正在下载... {}/{} ({:.1}%)，{}/s{}",
+            format_bytes(info.current),
+            format_bytes(info.total),
+            percent(info),
+            format_bytes(info.bytes_per_second),
+            info.eta_secs
+                .map(|eta| format!("，剩余 {}", format_duration_zh(eta)))
+                .unwrap_or_default()
+        ),
+        ProgressStage::DownloadFinished => "下载完成，正在导出文件...".to_string(),
+        ProgressStage::ExportingFile => format!("正在导出 {}", name),
+        ProgressStage::ExportFinished => "导出完成".to_string(),
+        ProgressStage::ReceiveFinished => "文件接收完成！".to_string(),
+    }
+}
+
+fn render_en(info: &ProgressInfo) -> String {
+    let name = info.file_name.as_deref().unwrap_or_default();
+    match info.stage {
+        ProgressStage::ImportStarted => format!("Importing {} files", info.file_count),
+        ProgressStage::ImportingFile => format!(
+            "Importing {} ({}/{}), {}/s",
+            name,
+            info.files_done,
+            info.file_count,
+            format_bytes(info.bytes_per_second)
+        ),
+        ProgressStage::FileImported => format!(
+            "Imported {} ({}/{})",
+            name, info.files_done, info.file_count
+        ),
+        ProgressStage::ImportFinished => format!("Processed {} files", info.file_count),
+        ProgressStage::WaitingForReceiver => {
+            "Files imported, waiting for a receiver to connect...".to_string()
+        }
+        ProgressStage::ParsingTicket => "Parsing ticket...".to_string(),
+        ProgressStage::Connecting => "Connecting to sender...".to_string(),
+        ProgressStage::FetchingInfo => "Connected, fetching file list...".to_string(),
+        ProgressStage::ConnectionChanged => connection_en(info),
+        ProgressStage::DownloadStarted => format!(
+            "Downloading {} files, {} in total",
+            info.file_count,
+            format_bytes(info.total)
+        ),
+        ProgressStage::DownloadResumed => format!(
+            "Resuming {} files, {} of {} already downloaded",
+            info.file_count,
+            format_bytes(info.current),
+            format_bytes(info.total)
+        ),
+        ProgressStage::Downloading => format!(
+            "Downloading... {}/{} ({:.1}%), {}/s{}",
+            format_bytes(info.current),
+            format_bytes(info.total),
+            percent(info),
+            format_bytes(info.bytes_per_second),
+            info.eta_secs
+                .map(|eta| format!(", {} left", format_duration_en(eta)))
+                .unwrap_or_default()
+        ),
+        ProgressStage::DownloadFinished => "Download complete, exporting files...".to_string(),
+        ProgressStage::ExportingFile => format!("Exporting {}", name),
+        ProgressStage::ExportFinished => "Export complete".to_string(),
+        ProgressStage::ReceiveFinished => "Files received!".to_string(),
+    }
+}
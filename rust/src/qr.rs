@@ -0,0 +1,72 @@
+use crate::{error::SendmeError, QrEcLevel, QrFormat, QrImage, QrOptions};
+use qrcode::{render::svg, Color, EcLevel, QrCode};
+
+// Light border around the code in modules, as the QR spec asks for
+const QUIET_ZONE: u32 = 4;
+// Keeps PNGs of long tickets within a few megapixels
+const MAX_MODULE_SIZE: u32 = 32;
+
+// Fails for options no code can be rendered with, before any work is done
+pub fn check_options(options: &QrOptions) -> anyhow::Result<()> {
+    if !(1..=MAX_MODULE_SIZE).contains(&options.module_size) {
+        return Err(SendmeError::InvalidConfig {
+            reason: format!(
+                "QR module size {} is not between 1 and {}",
+                options.module_size, MAX_MODULE_SIZE
+            ),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+// Renders `data`, usually a ticket, as a QR code
+pub fn qr_code(data: &str, options: &QrOptions) -> anyhow::Result<QrImage> {
+    check_options(options)?;
+    let ec_level = match options.ec_level {
+        QrEcLevel::L => EcLevel::L,
+        QrEcLevel::M => EcLevel::M,
+        QrEcLevel::Q => EcLevel::Q,
+        QrEcLevel::H => EcLevel::H,
+    };
+    let code = QrCode::with_error_correction_level(data.as_bytes(), ec_level)?;
+    let module_size = options.module_size;
+    Ok(match options.format {
+        QrFormat::Png => QrImage::Png {
+            bytes: encode_png(&code, module_size)?,
+        },
+        QrFormat::Svg => QrImage::Svg {
+            markup: code
+                .render::<svg::Color>()
+                .module_dimensions(module_size, module_size)
+                .build(),
+        },
+    })
+}
+
+// 8 bit grayscale, black modules on white
+fn encode_png(code: &QrCode, module_size: u32) -> anyhow::Result<Vec<u8>> {
+    let modules = code.width() as u32;
+    let size = (modules + 2 * QUIET_ZONE) * module_size;
+    let mut pixels = vec![255u8; (size * size) as usize];
+    for (i, color) in code.to_colors().into_iter().enumerate() {
+        if color != Color::Dark {
+            continue;
+        }
+        let x = (i as u32 % modules + QUIET_ZONE) * module_size;
+        let y = (i as u32 / modules + QUIET_ZONE) * module_size;
+        for row in y..y + module_size {
+            let start = (row * size + x) as usize;
+            pixels[start..start + module_size as usize].fill(0);
+        }
+    }
+
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, size, size);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+    Ok(png)
+}
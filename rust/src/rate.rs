@@ -0,0 +1,169 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+// How far back the rolling transfer rate looks
+const WINDOW: Duration = Duration::from_secs(5);
+// Rates over shorter spans are too noisy to count as a peak
+const MIN_PEAK_SPAN: Duration = Duration::from_secs(1);
+
+// Smoothed throughput of a single operation, fed with the absolute number of
+// bytes done so far
+pub struct RateTracker {
+    started: Instant,
+    start_bytes: u64,
+    samples: VecDeque<(Instant, u64)>,
+    peak: u64,
+}
+
+impl RateTracker {
+    // `start_bytes` are already done and do not count towards the rate, e.g.
+    // the part of a resumed download that is on disk
+    pub fn new(start_bytes: u64) -> Self {
+        let started = Instant::now();
+        Self {
+            started,
+            start_bytes,
+            samples: VecDeque::from([(started, start_bytes)]),
+            peak: 0,
+        }
+    }
+
+    pub fn record(&mut self, bytes: u64) {
+        self.record_at(Instant::now(), bytes);
+    }
+
+    fn record_at(&mut self, now: Instant, bytes: u64) {
+        self.samples.push_back((now, bytes));
+        // Keep one sample at or before the window start so the window is full
+        while self.samples.len() > 2 && now.duration_since(self.samples[1].0) >= WINDOW {
+            self.samples.pop_front();
+        }
+        if self.span() >= MIN_PEAK_SPAN {
+            self.peak = self.peak.max(self.bytes_per_second());
+        }
+    }
+
+    fn span(&self) -> Duration {
+        match (self.samples.front(), self.samples.back()) {
+            (Some((first, _)), Some((last, _))) => last.duration_since(*first),
+            _ => Duration::ZERO,
+        }
+    }
+
+    // Rate over the last few seconds
+    pub fn bytes_per_second(&self) -> u64 {
+        let (Some((_, first)), Some((_, last))) = (self.samples.front(), self.samples.back())
+        else {
+            return 0;
+        };
+        let span = self.span().as_secs_f64();
+        if span > 0.0 {
+            (last.saturating_sub(*first) as f64 / span) as u64
+        } else {
+            0
+        }
+    }
+
+    // None while there is no rate to extrapolate from
+    pub fn eta_secs(&self, remaining: u64) -> Option<u64> {
+        if remaining == 0 {
+            return Some(0);
+        }
+        match self.bytes_per_second() {
+            0 => None,
+            rate => Some(remaining.div_ceil(rate)),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    // Rate over the whole operation
+    pub fn average(&self) -> u64 {
+        self.average_at(Instant::now())
+    }
+
+    fn average_at(&self, now: Instant) -> u64 {
+        let bytes = self
+            .samples
+            .back()
+            .map_or(0, |(_, bytes)| bytes.saturating_sub(self.start_bytes));
+        let elapsed = now.duration_since(self.started).as_secs_f64();
+        if elapsed > 0.0 {
+            (bytes as f64 / elapsed) as u64
+        } else {
+            0
+        }
+    }
+
+    // Highest rolling rate seen, or the average for operations that were too
+    // short to have one
+    pub fn peak(&self) -> u64 {
+        self.peak.max(self.average())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(secs: f64) -> Duration {
+        Duration::from_secs_f64(secs)
+    }
+
+    #[test]
+    fn rate_only_looks_at_the_window() {
+        let mut rate = RateTracker::new(0);
+        let start = rate.started;
+        rate.record_at(start + secs(1.0), 1000);
+        assert_eq!(rate.bytes_per_second(), 1000);
+
+        // Stalled since the first second, which is now out of the window
+        for t in 2..=6 {
+            rate.record_at(start + secs(t as f64), 1000);
+        }
+        assert_eq!(rate.samples.front().unwrap().0, start + secs(1.0));
+        assert_eq!(rate.bytes_per_second(), 0);
+        assert_eq!(rate.average_at(start + secs(5.0)), 200);
+        assert_eq!(rate.peak, 1000);
+    }
+
+    #[test]
+    fn short_spans_are_no_peak() {
+        let mut rate = RateTracker::new(0);
+        let start = rate.started;
+        rate.record_at(start + secs(0.5), 5000);
+        assert_eq!(rate.bytes_per_second(), 10_000);
+        assert_eq!(rate.peak, 0);
+
+        rate.record_at(start + MIN_PEAK_SPAN, 6000);
+        assert_eq!(rate.peak, 6000);
+        rate.record_at(start + secs(2.0), 6500);
+        assert_eq!(rate.peak, 6000);
+    }
+
+    #[test]
+    fn eta_needs_a_rate() {
+        let mut rate = RateTracker::new(0);
+        assert_eq!(rate.eta_secs(0), Some(0));
+        assert_eq!(rate.eta_secs(100), None);
+
+        let start = rate.started;
+        rate.record_at(start + secs(2.0), 200);
+        assert_eq!(rate.eta_secs(250), Some(3));
+        assert_eq!(rate.eta_secs(0), Some(0));
+    }
+
+    #[test]
+    fn resumed_bytes_do_not_count() {
+        let mut rate = RateTracker::new(1000);
+        let start = rate.started;
+        rate.record_at(start + secs(2.0), 1400);
+        assert_eq!(rate.bytes_per_second(), 200);
+        assert_eq!(rate.average_at(start + secs(2.0)), 200);
+        assert_eq!(rate.average_at(start), 0);
+    }
+}
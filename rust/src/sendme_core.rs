@@ -1,31 +1,103 @@
-use crate::{ProgressInfo, ProgressOperation, ProgressSender, ReceiveResult, SendResult, SENDME_STATE};
+use crate::{
+    entry_names::safe_relative_path,
+    error::SendmeError,
+    node::{self, Node, Share},
+    qr,
+    rate::RateTracker,
+    short_code,
+    ConflictPolicy, ConnectionInfo, ConnectionPath, EndpointConfig, PartialDownload, ProgressInfo, ProgressOperation, ProgressStage, ProgressSender, ReceiveOptions,
+    ReceiveResult, RelayModeOption, SendHandle, SendOptions, SendResult, StopSharingResult, TicketFormat, TicketInfo, TicketWarning, TransferFile, TransferId, TransferInfo, TransferKind, TransferState, SENDME_STATE,
+};
 use anyhow::Context;
 use data_encoding::HEXLOWER;
-use iroh::{Endpoint, SecretKey};
+use iroh::{
+    discovery::{dns::DnsDiscovery, pkarr::PkarrPublisher},
+    endpoint::ConnectionType,
+    Endpoint, EndpointAddr, EndpointId, RelayMap, RelayMode, RelayUrl, SecretKey, TransportAddr, Watcher,
+};
 use iroh_blobs::{
     api::{
-        blobs::{AddPathOptions, ExportMode, ExportOptions, ImportMode},
+        blobs::{AddPathOptions, BlobStatus, ExportMode, ExportOptions, ImportMode},
         remote::GetProgressItem,
         Store, TempTag,
     },
     format::collection::Collection,
-    get::request::get_hash_seq_and_sizes,
-    provider::events::{EventMask, EventSender},
+    get::request::{get_hash_seq_and_sizes, get_verified_size},
     store::fs::FsStore,
     ticket::BlobTicket,
-    BlobFormat, BlobsProtocol,
+    BlobFormat, Hash, HashAndFormat,
 };
-use n0_future::{BufferedStreamExt, StreamExt};
+use n0_future::{task::AbortOnDropHandle, BufferedStreamExt, StreamExt};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use rand::Rng;
+use std::future::Future;
 use std::str::FromStr;
 use std::{
-    path::PathBuf,
-    sync::{Arc, Mutex},
+    collections::{HashMap, HashSet},
+    net::{IpAddr, Ipv4Addr, SocketAddrV4, SocketAddrV6},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
     time::{Duration, Instant},
 };
-use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use walkdir::WalkDir;
 
+// How long a receiver waits for the sender to accept the connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Receive stores are named after the hash they fetch, so a later receive of
+// the same ticket finds whatever an earlier attempt left behind.
+const RECV_DIR_PREFIX: &str = ".sendme-recv-";
+// Written next to a receive store so a partial download can be resumed
+// without asking the user for the ticket again.
+const RECV_TICKET_FILE: &str = "ticket";
+
+// Raw blobs have no name of their own, so senders append the file name to
+// the ticket after this separator, percent-encoded
+const FILE_NAME_SEPARATOR: char = '#';
+// Unreserved URL characters stay readable
+const FILE_NAME_ENCODE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'.')
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'~');
+
+fn recv_store_dir(root: &Path, hash: &Hash) -> PathBuf {
+    root.join(format!("{}{}", RECV_DIR_PREFIX, hash.to_hex()))
+}
+
+// Resolves a user supplied directory, creating it if needed, and makes sure
+// we can actually write there before any transfer work starts.
+pub(crate) async fn writable_dir(dir: Option<&str>, what: &str) -> anyhow::Result<PathBuf> {
+    let dir = match dir {
+        Some(dir) => PathBuf::from(dir),
+        None => std::env::current_dir()?,
+    };
+    let io_error = |message: String| SendmeError::Io {
+        path: Some(dir.display().to_string()),
+        message,
+    };
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| io_error(format!("{} cannot be created: {}", what, e)))?;
+    if !dir.is_dir() {
+        return Err(io_error(format!("{} is not a directory", what)).into());
+    }
+
+    let suffix = rand::rng().random::<[u8; 8]>();
+    let probe = dir.join(format!(".sendme-probe-{}", HEXLOWER.encode(&suffix)));
+    tokio::fs::write(&probe, b"")
+        .await
+        .map_err(|e| io_error(format!("{} is not writable: {}", what, e)))?;
+    tokio::fs::remove_file(&probe).await?;
+
+    // The store only exports to absolute paths
+    Ok(tokio::fs::canonicalize(&dir).await?)
+}
+
 #[flutter_rust_bridge::frb(sync)]
 pub fn init_logging() {
     tracing_subscriber::fmt::init();
@@ -37,57 +109,234 @@ pub fn init_app() {
     init_logging();
 }
 
-fn get_or_create_secret() -> anyhow::Result<SecretKey> {
-    match std::env::var("IROH_SECRET") {
-        Ok(secret) => SecretKey::from_str(&secret).context("invalid secret"),
-        Err(_) => {
-            let key = SecretKey::generate(&mut rand::rng());
-            Ok(key)
+// Builds and binds an endpoint as configured by the app
+pub(crate) async fn bind_endpoint(
+    config: &EndpointConfig,
+    secret_key: SecretKey,
+    alpns: Vec<Vec<u8>>,
+) -> anyhow::Result<Endpoint> {
+    let invalid = |reason: String| SendmeError::InvalidConfig { reason };
+
+    let relay_mode = match config.relay_mode {
+        _ if config.lan_only => RelayMode::Disabled,
+        RelayModeOption::Default => RelayMode::Default,
+        RelayModeOption::Disabled => RelayMode::Disabled,
+        RelayModeOption::Custom => {
+            if config.relay_urls.is_empty() {
+                return Err(invalid("custom relay mode without relay URLs".to_string()).into());
+            }
+            let urls = config
+                .relay_urls
+                .iter()
+                .map(|url| {
+                    RelayUrl::from_str(url)
+                        .map_err(|e| invalid(format!("relay URL {}: {}", url, e)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            RelayMode::Custom(urls.into_iter().collect::<RelayMap>())
+        }
+    };
+
+    let mut builder = Endpoint::empty_builder(relay_mode)
+        .alpns(alpns)
+        .secret_key(secret_key);
+    if config.dns_discovery && !config.lan_only {
+        builder = builder.discovery(DnsDiscovery::n0_dns());
+    }
+    if config.pkarr_publishing && !config.lan_only {
+        builder = builder.discovery(PkarrPublisher::n0_dns());
+    }
+    if config.mdns_discovery || config.lan_only {
+        #[cfg(feature = "discovery-local-network")]
+        {
+            builder = builder.discovery(iroh::discovery::mdns::MdnsDiscovery::builder());
+        }
+        #[cfg(not(feature = "discovery-local-network"))]
+        return Err(invalid("built without local network discovery".to_string()).into());
+    }
+
+    let port = config.bind_port.unwrap_or(0);
+    match config.bind_addr.as_deref() {
+        Some(addr) => match IpAddr::from_str(addr) {
+            Ok(IpAddr::V4(ip)) => builder = builder.bind_addr_v4(SocketAddrV4::new(ip, port)),
+            Ok(IpAddr::V6(ip)) => builder = builder.bind_addr_v6(SocketAddrV6::new(ip, port, 0, 0)),
+            Err(e) => return Err(invalid(format!("bind address {}: {}", addr, e)).into()),
+        },
+        None if port != 0 => {
+            builder = builder.bind_addr_v4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, port));
         }
+        None => {}
     }
+
+    Ok(builder.bind().await?)
 }
 
-async fn import_with_progress(
-    path: PathBuf,
-    db: &Store,
-    progress_sender: ProgressSender,
-) -> anyhow::Result<(TempTag, u64, Collection)> {
-    let parallelism = num_cpus::get();
-    let path = path.canonicalize()?;
-    anyhow::ensure!(path.exists(), "path {} does not exist", path.display());
-    let root = path.parent().context("get parent")?;
+// Shortest interval between two byte level progress updates
+pub(crate) const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+// Aggregates the byte progress of the imports running in parallel
+struct ImportProgress {
+    sender: ProgressSender,
+    rate: RateTracker,
+    last_sent: Option<Instant>,
+    total_bytes: u64,
+    total_files: u64,
+    hashed_bytes: u64,
+    files_done: u64,
+}
+
+impl ImportProgress {
+    fn new(sender: ProgressSender, total_bytes: u64, total_files: u64) -> Self {
+        Self {
+            sender,
+            rate: RateTracker::new(0),
+            last_sent: None,
+            total_bytes,
+            total_files,
+            hashed_bytes: 0,
+            files_done: 0,
+        }
+    }
+
+    // Ephemeral copy and hash events are throttled, finished files are not
+    fn advance(&mut self, name: &str, bytes: u64) {
+        self.hashed_bytes += bytes;
+        if self
+            .last_sent
+            .is_some_and(|sent| sent.elapsed() < PROGRESS_INTERVAL)
+        {
+            return;
+        }
+        self.send(ProgressStage::ImportingFile, name);
+    }
+
+    fn file_done(&mut self, name: &str, remaining_bytes: u64) {
+        self.hashed_bytes += remaining_bytes;
+        self.files_done += 1;
+        self.send(ProgressStage::FileImported, name);
+    }
+
+    fn send(&mut self, stage: ProgressStage, name: &str) {
+        self.last_sent = Some(Instant::now());
+        self.rate.record(self.hashed_bytes);
+        self.sender.send(
+            ProgressInfo::new(
+                self.sender.transfer_id(),
+                ProgressOperation::Import,
+                stage,
+                self.hashed_bytes.min(self.total_bytes),
+                self.total_bytes,
+            )
+            .with_file_count(self.total_files)
+            .with_files_done(self.files_done)
+            .with_file_name(name)
+            .with_rate(&self.rate),
+        );
+    }
+}
+
+// Gives every source a top level name from its file name. Names that are
+// already taken by an earlier source, ignoring case for receivers on case
+// insensitive file systems, get the first free " (n)" suffix.
+fn top_level_names(sources: &[PathBuf]) -> anyhow::Result<Vec<String>> {
+    let mut taken = HashSet::new();
+    let mut names = Vec::with_capacity(sources.len());
+    for source in sources {
+        let name = source
+            .file_name()
+            .with_context(|| format!("{} has no file name", source.display()))?
+            .to_string_lossy()
+            .to_string();
+        let (stem, extension) = match name.rsplit_once('.') {
+            Some((stem, extension)) if source.is_file() && !stem.is_empty() => {
+                (stem.to_string(), Some(extension.to_string()))
+            }
+            _ => (name.clone(), None),
+        };
+        let mut candidate = name;
+        let mut n = 1u64;
+        while !taken.insert(candidate.to_lowercase()) {
+            candidate = match &extension {
+                Some(extension) => format!("{} ({}).{}", stem, n, extension),
+                None => format!("{} ({})", stem, n),
+            };
+            n += 1;
+        }
+        names.push(candidate);
+    }
+    Ok(names)
+}
 
-    let files = WalkDir::new(path.clone()).into_iter();
-    let data_sources: Vec<(String, PathBuf)> = files
-        .map(|entry| {
+// Every file below the given paths with its collection name and size.
+// Paths that were picked twice are only sent once.
+fn collect_sources(paths: &[PathBuf]) -> anyhow::Result<Vec<(String, PathBuf, u64)>> {
+    anyhow::ensure!(!paths.is_empty(), "nothing to send");
+    let mut roots = Vec::new();
+    for path in paths {
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("path {} does not exist", path.display()))?;
+        if !roots.contains(&path) {
+            roots.push(path);
+        }
+    }
+
+    let mut sources = Vec::new();
+    for (root, top) in roots.iter().zip(top_level_names(&roots)?) {
+        for entry in WalkDir::new(root) {
             let entry = entry?;
             if !entry.file_type().is_file() {
-                return Ok(None);
+                continue;
             }
+            let len = entry.metadata()?.len();
             let path = entry.into_path();
-            let relative = path.strip_prefix(root)?;
-            let name = relative.to_string_lossy().to_string();
-            anyhow::Ok(Some((name, path)))
-        })
-        .filter_map(Result::transpose)
-        .collect::<anyhow::Result<Vec<_>>>()?;
+            // A picked file is its own root and goes by its top level name
+            let name = if path == *root {
+                top.clone()
+            } else {
+                Path::new(&top)
+                    .join(path.strip_prefix(root)?)
+                    .to_string_lossy()
+                    .to_string()
+            };
+            sources.push((name, path, len));
+        }
+    }
+    Ok(sources)
+}
+
+// Adds every file below `paths` to the store, sorted by collection name
+async fn import_with_progress(
+    paths: Vec<PathBuf>,
+    db: &Store,
+    progress_sender: ProgressSender,
+) -> anyhow::Result<Vec<(String, TempTag, u64)>> {
+    let parallelism = num_cpus::get();
+    let data_sources = collect_sources(&paths)?;
 
     let total_files = data_sources.len() as u64;
-    let mut processed_files = 0u64;
-
-    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-        let _ = sender.send(ProgressInfo {
-            operation: ProgressOperation::Import,
-            current: 0,
-            total: total_files,
-            message: format!("正在导入 {} 个文件", total_files),
-        });
-    }
+    let total_bytes = data_sources.iter().map(|(_, _, len)| *len).sum::<u64>();
+
+    progress_sender.send(
+        ProgressInfo::new(
+            progress_sender.transfer_id(),
+            ProgressOperation::Import,
+            ProgressStage::ImportStarted,
+            0,
+            total_bytes,
+        )
+        .with_file_count(total_files),
+    );
 
+    let progress = Mutex::new(ImportProgress::new(
+        progress_sender.clone(),
+        total_bytes,
+        total_files,
+    ));
+    let progress = &progress;
     let mut names_and_tags: Vec<(String, TempTag, u64)> = n0_future::stream::iter(data_sources)
-        .map(|(name, path)| {
+        .map(|(name, path, _)| {
             let db = db.clone();
-            let progress_sender = progress_sender.clone();
             async move {
                 let import = db.add_path_with_opts(AddPathOptions {
                     path,
@@ -96,6 +345,7 @@ async fn import_with_progress(
                 });
                 let mut stream = import.stream().await;
                 let mut item_size = 0;
+                let mut hashed = 0;
                 let temp_tag = loop {
                     let item = stream
                         .next()
@@ -105,7 +355,21 @@ async fn import_with_progress(
                         iroh_blobs::api::blobs::AddProgressItem::Size(size) => {
                             item_size = size;
                         }
+                        iroh_blobs::api::blobs::AddProgressItem::CopyProgress(_) => {
+                            // Copying is not counted in bytes, but still shows
+                            // which file is being worked on
+                            progress.lock().unwrap().advance(&name, 0);
+                        }
+                        iroh_blobs::api::blobs::AddProgressItem::OutboardProgress(offset) => {
+                            let delta = offset.saturating_sub(hashed);
+                            hashed = hashed.max(offset);
+                            progress.lock().unwrap().advance(&name, delta);
+                        }
                         iroh_blobs::api::blobs::AddProgressItem::Done(tt) => {
+                            progress
+                                .lock()
+                                .unwrap()
+                                .file_done(&name, item_size.saturating_sub(hashed));
                             break tt;
                         }
                         iroh_blobs::api::blobs::AddProgressItem::Error(cause) => {
@@ -123,334 +387,1082 @@ async fn import_with_progress(
         .into_iter()
         .collect::<anyhow::Result<Vec<_>>>()?;
 
-    processed_files = names_and_tags.len() as u64;
+    let processed_files = names_and_tags.len() as u64;
 
-    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-        let _ = sender.send(ProgressInfo {
-            operation: ProgressOperation::Import,
-            current: processed_files,
-            total: total_files,
-            message: format!("已处理 {} 个文件", processed_files),
-        });
-    }
+    progress_sender.send(
+        ProgressInfo::new(
+            progress_sender.transfer_id(),
+            ProgressOperation::Import,
+            ProgressStage::ImportFinished,
+            total_bytes,
+            total_bytes,
+        )
+        .with_file_count(processed_files)
+        .with_files_done(processed_files),
+    );
 
     names_and_tags
         .sort_by(|(a, _, _): &(String, TempTag, u64), (b, _, _): &(String, TempTag, u64)| a.cmp(b));
-    let size = names_and_tags.iter().map(|(_, _, size)| *size).sum::<u64>();
-    let (collection, tags) = names_and_tags
+    Ok(names_and_tags)
+}
+
+// Stores the imported files as a collection, whose tag keeps all of them
+async fn store_collection(
+    db: &Store,
+    files: Vec<(String, TempTag, u64)>,
+) -> anyhow::Result<(TempTag, u64, Collection)> {
+    let size = files.iter().map(|(_, _, size)| *size).sum::<u64>();
+    let (collection, tags) = files
         .into_iter()
-        .map(|(name, tag, _): (String, TempTag, u64)| ((name, tag.hash()), tag))
+        .map(|(name, tag, _)| ((name, tag.hash()), tag))
         .unzip::<_, _, Collection, Vec<_>>();
     let temp_tag = collection.clone().store(db).await?;
     drop(tags);
-
     Ok((temp_tag, size, collection))
 }
 
+// Targets taken by earlier entries of the same collection. Paths are kept
+// lower case, so entries that only differ in case do not end up in the same
+// file on case insensitive file systems.
+#[derive(Default)]
+struct Claimed {
+    files: HashMap<PathBuf, Hash>,
+    dirs: HashSet<PathBuf>,
+}
+
+impl Claimed {
+    fn contains(&self, path: &Path) -> bool {
+        let key = fold_case(path);
+        self.files.contains_key(&key) || self.dirs.contains(&key)
+    }
+
+    fn claim(&mut self, root: &Path, target: &Path, hash: Hash) {
+        self.files.insert(fold_case(target), hash);
+        for dir in ancestors_below(root, target) {
+            self.dirs.insert(fold_case(&dir));
+        }
+    }
+}
+
+fn fold_case(path: &Path) -> PathBuf {
+    PathBuf::from(path.to_string_lossy().to_lowercase())
+}
+
+// The directories between `root` and `target` that the export creates
+fn ancestors_below(root: &Path, target: &Path) -> Vec<PathBuf> {
+    let mut dirs = target
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| *dir != root)
+        .map(Path::to_path_buf)
+        .collect::<Vec<_>>();
+    dirs.reverse();
+    dirs
+}
+
+// A file, on disk or claimed by an earlier entry, where `target` needs a
+// directory
+fn blocking_file(root: &Path, target: &Path, claimed: &Claimed) -> Option<PathBuf> {
+    ancestors_below(root, target).into_iter().find(|dir| {
+        (dir.exists() && !dir.is_dir()) || claimed.files.contains_key(&fold_case(dir))
+    })
+}
+
+// Decides where every collection entry goes before anything is written, so
+// a conflict never leaves a half exported tree behind. Entries without a
+// target are skipped.
+async fn plan_export(
+    collection: &Collection,
+    root: &Path,
+    policy: ConflictPolicy,
+) -> anyhow::Result<Vec<(String, Hash, Option<PathBuf>)>> {
+    // Names come straight from the sender, check all of them up front
+    let targets = collection
+        .iter()
+        .map(|(name, _)| Ok(root.join(safe_relative_path(name)?)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut claimed = Claimed::default();
+    let mut plan = Vec::new();
+    let target_exists = |path: &Path| SendmeError::TargetExists {
+        path: path.display().to_string(),
+    };
+
+    for ((name, hash), target) in collection.iter().zip(targets) {
+        // Renaming the file would not help, its directory cannot be created
+        if let Some(file) = blocking_file(root, &target, &claimed) {
+            match policy {
+                ConflictPolicy::Skip => {
+                    plan.push((name.clone(), *hash, None));
+                    continue;
+                }
+                _ => return Err(target_exists(&file).into()),
+            }
+        }
+
+        let claimed_before = claimed.contains(&target);
+        let target = if !target.exists() && !claimed_before {
+            Some(target)
+        } else {
+            match policy {
+                // Only files that were there before, not other entries
+                ConflictPolicy::Overwrite if target.is_file() && !claimed_before => Some(target),
+                ConflictPolicy::Skip => None,
+                ConflictPolicy::RenameWithSuffix => Some(unique_target(&target, &claimed)),
+                ConflictPolicy::SkipIfIdenticalHash
+                    if claimed.files.get(&fold_case(&target)) == Some(hash) =>
+                {
+                    None
+                }
+                ConflictPolicy::SkipIfIdenticalHash
+                    if !claimed_before
+                        && target.is_file()
+                        && file_hash(&target).await? == *hash =>
+                {
+                    None
+                }
+                _ => return Err(target_exists(&target).into()),
+            }
+        };
+        if let Some(target) = &target {
+            claimed.claim(root, target, *hash);
+        }
+        plan.push((name.clone(), *hash, target));
+    }
+
+    Ok(plan)
+}
+
+// Finds the first free "name (n).ext" next to `target`
+fn unique_target(target: &Path, claimed: &Claimed) -> PathBuf {
+    let stem = target
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = target
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_string());
+
+    let mut n = 1u64;
+    loop {
+        let file_name = match &extension {
+            Some(extension) => format!("{} ({}).{}", stem, n, extension),
+            None => format!("{} ({})", stem, n),
+        };
+        let candidate = target.with_file_name(file_name);
+        if !candidate.exists() && !claimed.contains(&candidate) {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+// A hidden file next to `target` that an export is written to first. The
+// name does not depend on the target, which may already be as long as the
+// file system allows.
+fn partial_target(target: &Path) -> PathBuf {
+    let suffix = rand::rng().random::<[u8; 8]>();
+    target.with_file_name(format!(".sendme-part-{}", HEXLOWER.encode(&suffix)))
+}
+
+// BLAKE3 of a file on disk, which is what iroh uses as the hash of a raw blob
+async fn file_hash(path: &Path) -> anyhow::Result<Hash> {
+    let path = path.to_owned();
+    tokio::task::spawn_blocking(move || {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update_reader(std::fs::File::open(&path)?)?;
+        anyhow::Ok(Hash::from(hasher.finalize()))
+    })
+    .await?
+}
+
 async fn export_with_progress(
     db: &Store,
     collection: Collection,
+    root: &Path,
+    conflict_policy: ConflictPolicy,
     progress_sender: ProgressSender,
+    cancel: &CancellationToken,
 ) -> anyhow::Result<()> {
-    let root = std::env::current_dir()?;
     let total_files = collection.len() as u64;
-    let mut processed_files = 0u64;
-
-    for (i, (name, hash)) in collection.iter().enumerate() {
-        processed_files = i as u64;
+    let plan = plan_export(&collection, root, conflict_policy).await?;
+    let sizes = blob_sizes(db, &collection).await?;
+    SENDME_STATE.set_transfer_files(
+        progress_sender.transfer_id(),
+        collection
+            .iter()
+            .zip(&sizes)
+            .map(|((name, _), size)| TransferFile {
+                name: name.clone(),
+                size: *size,
+            })
+            .collect(),
+    );
 
-        if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-            let _ = sender.send(ProgressInfo {
-                operation: ProgressOperation::Export,
-                current: processed_files,
-                total: total_files,
-                message: format!("正在导出 {}", name),
-            });
-        }
+    // Skipped files are neither copied nor counted
+    let total_bytes = plan
+        .iter()
+        .zip(&sizes)
+        .filter(|((_, _, target), _)| target.is_some())
+        .map(|(_, size)| *size)
+        .sum::<u64>();
+    let mut rate = RateTracker::new(0);
+    let mut copied = 0;
+    let mut send_progress = |stage, name: &str, files_done, file_current, file_total, copied| {
+        rate.record(copied);
+        progress_sender.send(
+            ProgressInfo::new(
+                progress_sender.transfer_id(),
+                ProgressOperation::Export,
+                stage,
+                copied,
+                total_bytes,
+            )
+            .with_file_count(total_files)
+            .with_files_done(files_done)
+            .with_file_name(name)
+            .with_file_progress(file_current, file_total)
+            .with_rate(&rate),
+        );
+    };
 
-        let target = root.join(name);
-        if target.exists() {
-            anyhow::bail!("target {} already exists", target.display());
-        }
+    for (i, ((name, hash, target), size)) in plan.iter().zip(sizes).enumerate() {
+        let Some(target) = target else {
+            continue;
+        };
+        send_progress(ProgressStage::ExportingFile, name, i as u64, 0, size, copied);
+        let mut last_sent = Instant::now();
 
+        let partial = partial_target(target);
         let mut stream = db
             .export_with_opts(ExportOptions {
                 hash: *hash,
-                target,
+                target: partial.clone(),
                 mode: ExportMode::Copy,
             })
             .stream()
             .await;
-
-        while let Some(item) = stream.next().await {
-            match item {
-                iroh_blobs::api::blobs::ExportProgressItem::Done => {
-                    // File exported successfully
-                }
-                iroh_blobs::api::blobs::ExportProgressItem::Error(cause) => {
-                    anyhow::bail!("error exporting {}: {}", name, cause);
+        let exported = async {
+            loop {
+                match cancellable(cancel, stream.next()).await? {
+                    Some(iroh_blobs::api::blobs::ExportProgressItem::CopyProgress(offset))
+                        if last_sent.elapsed() >= PROGRESS_INTERVAL =>
+                    {
+                        let offset = offset.min(size);
+                        send_progress(
+                            ProgressStage::ExportingFile,
+                            name,
+                            i as u64,
+                            offset,
+                            size,
+                            copied + offset,
+                        );
+                        last_sent = Instant::now();
+                    }
+                    Some(iroh_blobs::api::blobs::ExportProgressItem::Done) => return Ok(()),
+                    Some(iroh_blobs::api::blobs::ExportProgressItem::Error(cause)) => {
+                        anyhow::bail!("error exporting {}: {}", name, cause);
+                    }
+                    Some(_) => {}
+                    None => {
+                        return Err(SendmeError::Io {
+                            path: Some(target.display().to_string()),
+                            message: "export ended before the file was complete".to_string(),
+                        }
+                        .into())
+                    }
                 }
-                _ => {}
             }
         }
-    }
+        .await;
+        drop(stream);
 
-    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-        let _ = sender.send(ProgressInfo {
-            operation: ProgressOperation::Export,
-            current: total_files,
-            total: total_files,
-            message: "导出完成".to_string(),
-        });
+        // Whatever was at the target stays untouched unless the copy is
+        // complete, also when it gets overwritten
+        if let Err(e) = exported {
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(e);
+        }
+        tokio::fs::rename(&partial, target).await?;
+        copied += size;
     }
 
+    progress_sender.send(
+        ProgressInfo::new(
+            progress_sender.transfer_id(),
+            ProgressOperation::Export,
+            ProgressStage::ExportFinished,
+            total_bytes,
+            total_bytes,
+        )
+        .with_file_count(total_files)
+        .with_files_done(total_files),
+    );
+
     Ok(())
 }
 
+// Sizes of the blobs of a collection, in collection order
+async fn blob_sizes(db: &Store, collection: &Collection) -> anyhow::Result<Vec<u64>> {
+    let mut sizes = Vec::with_capacity(collection.len());
+    for (name, hash) in collection.iter() {
+        let size = match db.blobs().status(*hash).await? {
+            BlobStatus::Complete { size } => size,
+            _ => {
+                return Err(SendmeError::StoreCorrupt {
+                    hash: hash.to_hex().to_string(),
+                    message: format!("{} is not fully downloaded", name),
+                }
+                .into())
+            }
+        };
+        sizes.push(size);
+    }
+    Ok(sizes)
+}
+
 #[flutter_rust_bridge::frb]
-pub async fn send_file(path: String) -> anyhow::Result<SendResult> {
-    println!("send_file called with path: {}", path);
-    let path = PathBuf::from(path);
-    let secret_key = get_or_create_secret()?;
-    println!("Secret key created successfully");
-
-    let suffix = rand::rng().random::<[u8; 16]>();
-    let cwd = std::env::current_dir()?;
-    let blobs_data_dir = cwd.join(format!(".sendme-send-{}", HEXLOWER.encode(&suffix)));
-
-    tokio::fs::create_dir_all(&blobs_data_dir).await?;
-    let store = FsStore::load(&blobs_data_dir).await?;
-    println!("Store created successfully");
-
-    // Enable progress tracking with a real sender
-    let (progress_stream, progress_sender) = crate::ProgressStream::new();
-
-    // Store the progress stream globally for access
-    let stream_clone = progress_stream;
-    SENDME_STATE.add_sender("progress_stream".to_string(), Box::new(stream_clone));
-
-    let (temp_tag, size, collection) = import_with_progress(
-        path.clone(),
-        &store,
-        progress_sender.clone(),
-    )
-    .await?;
-    let hash = temp_tag.hash();
-    println!("File imported successfully, hash: {}", hash.to_hex());
+pub async fn send_file(path: String, options: SendOptions) -> anyhow::Result<SendResult> {
+    send_files(vec![path], options).await
+}
 
-    // Send completion progress
-    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-        let _ = sender.send(crate::ProgressInfo {
-            operation: crate::ProgressOperation::Import,
-            current: 1,
-            total: 1,
-            message: "文件导入完成，正在等待接收方连接...".to_string(),
-        });
+#[flutter_rust_bridge::frb]
+pub async fn send_files(paths: Vec<String>, options: SendOptions) -> anyhow::Result<SendResult> {
+    if let Some(qr) = &options.qr {
+        qr::check_options(qr)?;
     }
+    if options.raw && !matches!(paths.as_slice(), [path] if Path::new(path).is_file()) {
+        return Err(SendmeError::InvalidConfig {
+            reason: format!("{} is not a single file, it cannot be sent raw", paths.join(", ")),
+        }
+        .into());
+    }
+    let transfer_id = SENDME_STATE.start_transfer(TransferKind::Send);
+    let cancel = SENDME_STATE.cancel_token(transfer_id);
+    let result = send_transfer(transfer_id, paths, options, &cancel).await;
+    match result {
+        Ok(result) => Ok(result),
+        Err(_) if cancel.is_cancelled() => {
+            SENDME_STATE.set_transfer_state(transfer_id, TransferState::Cancelled);
+            Err(SendmeError::Cancelled { transfer_id }.into())
+        }
+        Err(e) => {
+            SENDME_STATE.fail_transfer(transfer_id, &e);
+            Err(e)
+        }
+    }
+}
 
-    println!("Creating endpoint...");
-    let endpoint = Endpoint::builder()
-        .alpns(vec![iroh_blobs::protocol::ALPN.to_vec()])
-        .secret_key(secret_key)
-        .bind()
-        .await?;
-    println!("Endpoint created successfully");
+async fn send_transfer(
+    transfer_id: TransferId,
+    paths: Vec<String>,
+    options: SendOptions,
+    cancel: &CancellationToken,
+) -> anyhow::Result<SendResult> {
+    tracing::debug!("sending {:?}", paths);
+    let paths = paths.into_iter().map(PathBuf::from).collect();
+    let node = node::node()?;
 
-    let blobs = BlobsProtocol::new(
-        &store,
-        Some(EventSender::new(mpsc::channel(32).0, EventMask::default())),
-    );
+    // Report progress to whoever is listening on the Dart side
+    let progress_sender = SENDME_STATE.progress_sender(transfer_id);
 
-    println!("Creating router...");
-    let router = iroh::protocol::Router::builder(endpoint)
-        .accept(iroh_blobs::ALPN, blobs.clone())
-        .spawn();
-    println!("Router created successfully");
-
-    // Skip the endpoint online check and add a delay instead
-    println!("Skipping endpoint online check, using delay instead...");
-    tokio::time::sleep(Duration::from_secs(1)).await;
-    println!("Continuing after delay...");
-
-    let addr = router.endpoint().addr();
-    println!("Got endpoint address: {:?}", addr);
-    println!("Creating ticket for file transfer...");
-    let ticket = BlobTicket::new(addr, hash, BlobFormat::HashSeq);
-    println!("Created ticket: {}", ticket.to_string());
-    let file_count = collection.len() as u64;
+    // Whatever a failed import added to the store is collected once its tags
+    // are dropped
+    let files = cancellable(
+        cancel,
+        import_with_progress(paths, &node.store, progress_sender.clone()),
+    )
+    .await??;
+    let file_count = files.len() as u64;
+    let (temp_tag, size, format, children, file_name) = if options.raw {
+        let (name, tag, size) = files.into_iter().next().context("no file to send")?;
+        (tag, size, BlobFormat::Raw, Vec::new(), Some(name))
+    } else {
+        let (tag, size, collection) = store_collection(&node.store, files).await?;
+        let children = collection.iter().map(|(_, hash)| *hash).collect();
+        (tag, size, BlobFormat::HashSeq, children, None)
+    };
+    let hash = temp_tag.hash();
+    tracing::debug!("imported {}", hash.to_hex());
+
+    // Send completion progress
+    progress_sender.send(ProgressInfo::new(
+        progress_sender.transfer_id(),
+        ProgressOperation::Import,
+        ProgressStage::WaitingForReceiver,
+        1,
+        1,
+    ));
 
-    let ticket_string = ticket.to_string();
+    let mut addr = cancellable(cancel, wait_until_reachable(&node)).await?;
+    if node.config.lan_only {
+        // Relays are disabled anyway, keep the ticket short
+        addr = EndpointAddr::from_parts(
+            addr.id,
+            addr.ip_addrs().map(|addr| TransportAddr::Ip(*addr)).collect::<Vec<_>>(),
+        );
+    }
+    let ticket = BlobTicket::new(addr, hash, format);
+    tracing::debug!("created ticket {}", ticket);
+
+    let ticket_string = ticket_with_file_name(&ticket, file_name.as_deref());
+    let qr = options
+        .qr
+        .map(|qr| qr::qr_code(&ticket_string, &qr))
+        .transpose()?;
     let result = SendResult {
+        transfer_id,
         ticket: ticket_string.clone(),
         hash: hash.to_hex().to_string(),
         size,
         file_count,
+        warning: ticket_warning(ticket.addr(), &node.config),
+        qr,
     };
 
-    // Store the sender in global state to keep it alive
-    // We use a tuple to keep both router and temp_tag alive
-    let sender_tuple = (router, temp_tag, progress_sender);
-    SENDME_STATE.add_sender(ticket_string.clone(), Box::new(sender_tuple));
+    // `cancel_transfer` still sees the send as importing, so a cancel that
+    // came in while waiting for the endpoint only set the token
+    if cancel.is_cancelled() {
+        return Err(SendmeError::Cancelled { transfer_id }.into());
+    }
 
-    println!("Sender setup complete. Keeping connection alive for ticket: {}", ticket_string);
-    println!("Waiting for receiver to connect...");
+    // Keep the collection in the store, then register it with the node. From
+    // then on receivers move the state on.
+    let connected_receivers = Arc::new(AtomicUsize::new(0));
+    let handle = SendHandle {
+        temp_tag,
+        connected_receivers: connected_receivers.clone(),
+    };
+    SENDME_STATE.set_send_handle(transfer_id, ticket_string.clone(), handle);
+    SENDME_STATE.set_transfer_state(transfer_id, TransferState::Waiting);
+    node.share(
+        transfer_id,
+        Share {
+            hashes: children.into_iter().chain(std::iter::once(hash)).collect(),
+            total: size,
+            connected_receivers,
+            requests: Arc::new(AtomicUsize::new(0)),
+            uploads: tokio::task::JoinSet::new(),
+        },
+    );
 
     Ok(result)
 }
 
+// Waits until the node can be reached from other networks: connected to its
+// home relay and with a local address, or just the latter without relays.
+// After the configured timeout it goes with whatever addresses it has.
+async fn wait_until_reachable(node: &Node) -> EndpointAddr {
+    let endpoint = node.endpoint();
+    let ready = async {
+        if node.config.relays_enabled() {
+            endpoint.online().await;
+        } else {
+            let mut addr = endpoint.watch_addr();
+            while addr.get().ip_addrs().next().is_none() {
+                if addr.updated().await.is_err() {
+                    break;
+                }
+            }
+        }
+    };
+    let timeout = Duration::from_millis(node.config.ready_timeout_ms);
+    if tokio::time::timeout(timeout, ready).await.is_err() {
+        tracing::warn!("endpoint not fully online after {:?}", timeout);
+    }
+    endpoint.addr()
+}
+
+fn ticket_warning(addr: &EndpointAddr, config: &EndpointConfig) -> Option<TicketWarning> {
+    let relay = addr.relay_urls().next().is_some();
+    let direct = addr.ip_addrs().next().is_some();
+    match (relay, direct) {
+        (true, true) => None,
+        (true, false) => Some(TicketWarning::RelayOnly),
+        // Nothing else to expect with relays turned off
+        (false, true) if !config.relays_enabled() => None,
+        (false, true) => Some(TicketWarning::DirectOnly),
+        (false, false) => Some(TicketWarning::NoAddresses),
+    }
+}
+
 #[flutter_rust_bridge::frb]
-pub async fn receive_file(ticket: String) -> anyhow::Result<ReceiveResult> {
-    let ticket = BlobTicket::from_str(&ticket)?;
-    let secret_key = get_or_create_secret()?;
+pub async fn stop_sharing(ticket_or_id: String) -> anyhow::Result<StopSharingResult> {
+    let (transfer_id, handle) = SENDME_STATE
+        .take_send_handle(&ticket_or_id)
+        .ok_or_else(|| SendmeError::UnknownTransfer {
+            id_or_ticket: ticket_or_id.clone(),
+        })?;
+    let SendHandle {
+        temp_tag,
+        connected_receivers,
+    } = handle;
+    let connected_receivers = connected_receivers.load(Ordering::Relaxed) as u64;
 
-    // Enable progress tracking
-    let (progress_stream, progress_sender) = crate::ProgressStream::new();
+    // New requests are rejected and running uploads aborted right away, the
+    // data goes with the next garbage collection
+    if let Some(node) = SENDME_STATE.node() {
+        node.unshare(transfer_id);
+    }
+    drop(temp_tag);
+    if let Some(code) = SENDME_STATE.transfer(transfer_id).and_then(|info| info.short_code) {
+        short_code::unpublish_short_code(code).await;
+    }
+    SENDME_STATE.set_transfer_state(transfer_id, TransferState::Done);
 
-    // Store the progress stream globally for access
-    SENDME_STATE.add_sender("receive_progress".to_string(), Box::new(progress_stream));
+    Ok(StopSharingResult {
+        transfer_id,
+        connected_receivers,
+    })
+}
 
-    // Send initial progress
-    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-        let _ = sender.send(crate::ProgressInfo {
-            operation: crate::ProgressOperation::Connect,
-            current: 0,
-            total: 1,
-            message: "正在解析 ticket...".to_string(),
-        });
+// Registers a receive, so the app knows its ID and can cancel it before
+// `receive_file` is done
+#[flutter_rust_bridge::frb]
+pub fn create_receive() -> TransferId {
+    SENDME_STATE.start_transfer(TransferKind::Receive)
+}
+
+#[flutter_rust_bridge::frb]
+pub async fn receive_file(
+    transfer_id: TransferId,
+    ticket: String,
+    options: ReceiveOptions,
+) -> anyhow::Result<ReceiveResult> {
+    match SENDME_STATE.begin_receive(transfer_id) {
+        Ok(()) => {}
+        // Cancelled before it started
+        Err(Some(TransferState::Cancelled)) => {
+            return Err(SendmeError::Cancelled { transfer_id }.into())
+        }
+        Err(_) => {
+            return Err(SendmeError::UnknownTransfer {
+                id_or_ticket: transfer_id.to_string(),
+            }
+            .into())
+        }
     }
+    let cancel = SENDME_STATE.cancel_token(transfer_id);
+    let result = receive_transfer(transfer_id, ticket, options, &cancel).await;
+    match result {
+        Ok(result) => {
+            SENDME_STATE.set_transfer_state(transfer_id, TransferState::Done);
+            Ok(result)
+        }
+        Err(_) if cancel.is_cancelled() => {
+            SENDME_STATE.set_transfer_state(transfer_id, TransferState::Cancelled);
+            Err(SendmeError::Cancelled { transfer_id }.into())
+        }
+        Err(e) => {
+            SENDME_STATE.fail_transfer(transfer_id, &e);
+            Err(e)
+        }
+    }
+}
 
-    let endpoint = Endpoint::builder()
-        .alpns(vec![])
-        .secret_key(secret_key)
-        .bind()
-        .await?;
+async fn receive_transfer(
+    transfer_id: TransferId,
+    ticket: String,
+    options: ReceiveOptions,
+    cancel: &CancellationToken,
+) -> anyhow::Result<ReceiveResult> {
+    let ticket_string = cancellable(cancel, short_code::resolve_ticket(ticket)).await??;
+    SENDME_STATE.set_transfer_ticket(transfer_id, ticket_string.clone());
+    let (ticket, file_name) = parse_blob_ticket(&ticket_string)?;
+    let options = ReceiveOptions {
+        raw_file_name: options.raw_file_name.or(file_name),
+        ..options
+    };
+    let output_dir = writable_dir(options.output_dir.as_deref(), "output directory").await?;
+    let temp_dir = writable_dir(options.temp_dir.as_deref(), "temp directory").await?;
+    let node = node::node()?;
+
+    // Report progress to whoever is listening on the Dart side
+    let progress_sender = SENDME_STATE.progress_sender(transfer_id);
 
-    let dir_name = format!(".sendme-recv-{}", ticket.hash().to_hex());
-    let iroh_data_dir = std::env::current_dir()?.join(dir_name);
+    // Send initial progress
+    progress_sender.send(ProgressInfo::new(
+        progress_sender.transfer_id(),
+        ProgressOperation::Connect,
+        ProgressStage::ParsingTicket,
+        0,
+        1,
+    ));
+
+    let iroh_data_dir = recv_store_dir(&temp_dir, &ticket.hash());
+    tokio::fs::create_dir_all(&iroh_data_dir).await?;
+    tokio::fs::write(iroh_data_dir.join(RECV_TICKET_FILE), &ticket_string).await?;
     let store = FsStore::load(&iroh_data_dir).await?;
+    let t0 = Instant::now();
+
+    let result = async {
+        let received = download_and_export(
+            &ticket,
+            node.endpoint(),
+            &store,
+            &output_dir,
+            &options,
+            &progress_sender,
+            cancel,
+        )
+        .await?;
+        // Get the local data for the received collection
+        let local_data = store.remote().local(ticket.hash_and_format()).await?;
+        anyhow::Ok((received, local_data.local_bytes()))
+    }
+    .await;
+    // Closed on every path, a retry or `list_partial_downloads` opens the
+    // same directory again
+    let shutdown = store.shutdown().await;
+
+    let (received, size) = match result {
+        Ok(received) => received,
+        Err(e) => {
+            if let Err(shutdown_error) = shutdown {
+                tracing::warn!("failed to close receive store: {:#}", shutdown_error);
+            }
+            // Failed receives keep their store so they can be resumed later,
+            // cancelled ones only when asked to
+            if cancel.is_cancelled() && !SENDME_STATE.keep_partial(transfer_id) {
+                tokio::fs::remove_dir_all(&iroh_data_dir).await?;
+            }
+            return Err(e);
+        }
+    };
+    shutdown?;
+
+    // Send final completion progress
+    progress_sender.send(
+        ProgressInfo::new(
+            progress_sender.transfer_id(),
+            ProgressOperation::Export,
+            ProgressStage::ReceiveFinished,
+            1,
+            1,
+        )
+        .with_file_count(received.file_count),
+    );
+
+    let duration = t0.elapsed();
+
+    let result = ReceiveResult {
+        transfer_id,
+        file_count: received.file_count,
+        size,
+        duration_ms: duration.as_millis() as u64,
+        average_bytes_per_second: received.average_bytes_per_second,
+        peak_bytes_per_second: received.peak_bytes_per_second,
+        connection: received.connection,
+    };
+
+    tokio::fs::remove_dir_all(iroh_data_dir).await?;
+
+    Ok(result)
+}
+
+fn describe_connection(
+    endpoint: &Endpoint,
+    remote: EndpointId,
+    conn_type: ConnectionType,
+) -> ConnectionInfo {
+    let (path, direct_addr, relay_url) = match conn_type {
+        ConnectionType::Direct(addr) => (ConnectionPath::Direct, Some(addr.to_string()), None),
+        ConnectionType::Relay(url) => (ConnectionPath::Relay, None, Some(url.to_string())),
+        ConnectionType::Mixed(addr, url) => (
+            ConnectionPath::Mixed,
+            Some(addr.to_string()),
+            Some(url.to_string()),
+        ),
+        ConnectionType::None => (ConnectionPath::None, None, None),
+    };
+    ConnectionInfo {
+        remote_node_id: remote.to_string(),
+        path,
+        direct_addr,
+        relay_url,
+        rtt_ms: endpoint
+            .latency(remote)
+            .map(|latency| latency.as_millis() as u64),
+    }
+}
+
+// How we are connected to `remote` right now, None if we know nothing about it
+fn connection_info(endpoint: &Endpoint, remote: EndpointId) -> Option<ConnectionInfo> {
+    let conn_type = endpoint.conn_type(remote)?.get();
+    Some(describe_connection(endpoint, remote, conn_type))
+}
+
+// Reports every path change to the sender until the handle is dropped or the
+// endpoint is closed
+fn watch_connection(
+    endpoint: &Endpoint,
+    remote: EndpointId,
+    progress_sender: &ProgressSender,
+) -> Option<AbortOnDropHandle<()>> {
+    let mut watcher = endpoint.conn_type(remote)?;
+    let endpoint = endpoint.clone();
+    let progress_sender = progress_sender.clone();
+    let task = tokio::spawn(async move {
+        while let Ok(conn_type) = watcher.updated().await {
+            let connection = describe_connection(&endpoint, remote, conn_type);
+            progress_sender.send(
+                ProgressInfo::new(
+                    progress_sender.transfer_id(),
+                    ProgressOperation::Connect,
+                    ProgressStage::ConnectionChanged,
+                    3,
+                    3,
+                )
+                .with_connection(Some(connection)),
+            );
+        }
+    });
+    Some(AbortOnDropHandle::new(task))
+}
 
+// What `download_and_export` did
+struct Received {
+    file_count: u64,
+    // Download rates, 0 if nothing had to be fetched
+    average_bytes_per_second: u64,
+    peak_bytes_per_second: u64,
+    connection: Option<ConnectionInfo>,
+}
+
+// Fetches whatever is missing from the local store and exports the
+// collection, or the raw blob as `options.raw_file_name`.
+async fn download_and_export(
+    ticket: &BlobTicket,
+    endpoint: &Endpoint,
+    store: &Store,
+    output_dir: &Path,
+    options: &ReceiveOptions,
+    progress_sender: &ProgressSender,
+    cancel: &CancellationToken,
+) -> anyhow::Result<Received> {
+    let transfer_id = progress_sender.transfer_id();
     let hash_and_format = ticket.hash_and_format();
     let local = store.remote().local(hash_and_format).await?;
-    let t0 = Instant::now();
 
     // Send connection progress
-    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-        let _ = sender.send(crate::ProgressInfo {
-            operation: crate::ProgressOperation::Connect,
-            current: 1,
-            total: 3,
-            message: "正在连接到发送方...".to_string(),
-        });
-    }
+    progress_sender.send(ProgressInfo::new(
+        progress_sender.transfer_id(),
+        ProgressOperation::Connect,
+        ProgressStage::Connecting,
+        1,
+        3,
+    ));
 
-    if !local.is_complete() {
+    let remote = ticket.addr().id;
+    let (average_bytes_per_second, peak_bytes_per_second, connection) = if !local.is_complete() {
         // Add timeout for connection attempt
-        println!("Attempting to connect to sender at: {:?}", ticket.addr());
+        tracing::debug!("connecting to sender at {:?}", ticket.addr());
 
-        let connection = tokio::time::timeout(
-            Duration::from_secs(30), // 30 second timeout
-            endpoint.connect(ticket.addr().clone(), iroh_blobs::protocol::ALPN)
-        ).await
-        .map_err(|_| anyhow::anyhow!("连接超时：无法在30秒内连接到发送方。请确保：\n1. 发送方仍在运行\n2. 网络连接正常\n3. Ticket 正确且未过期\n4. 防火墙没有阻止连接"))??;
+        let node_id = ticket.addr().id.to_string();
+        let connection = cancellable(
+            cancel,
+            tokio::time::timeout(
+                CONNECT_TIMEOUT,
+                endpoint.connect(ticket.addr().clone(), iroh_blobs::protocol::ALPN),
+            ),
+        )
+        .await?
+        .map_err(|_| SendmeError::ConnectTimeout {
+            node_id: node_id.clone(),
+            timeout_secs: CONNECT_TIMEOUT.as_secs(),
+        })?
+        .map_err(|e| SendmeError::PeerUnreachable {
+            node_id,
+            reason: e.to_string(),
+        })?;
+        SENDME_STATE.set_transfer_state(transfer_id, TransferState::Connected);
+        let _watch = watch_connection(endpoint, remote, progress_sender);
 
         // Send connection established progress
-        if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-            let _ = sender.send(crate::ProgressInfo {
-                operation: crate::ProgressOperation::Connect,
-                current: 2,
-                total: 3,
-                message: "已连接，正在获取文件信息...".to_string(),
-            });
-        }
-
-        let (_hash_seq, sizes) =
-            get_hash_seq_and_sizes(&connection, &hash_and_format.hash, 1024 * 1024 * 32, None)
-                .await?;
+        progress_sender.send(
+            ProgressInfo::new(
+                progress_sender.transfer_id(),
+                ProgressOperation::Connect,
+                ProgressStage::FetchingInfo,
+                2,
+                3,
+            )
+            .with_connection(connection_info(endpoint, remote)),
+        );
 
-        let total_size = sizes.iter().copied().sum::<u64>();
-        let payload_size = sizes.iter().skip(2).copied().sum::<u64>();
-        let total_files = (sizes.len().saturating_sub(1)) as u64;
+        let (total_size, total_files) = match hash_and_format.format {
+            BlobFormat::HashSeq => {
+                let (hash_seq, sizes) = cancellable(
+                    cancel,
+                    get_hash_seq_and_sizes(&connection, &hash_and_format.hash, 1024 * 1024 * 32, None),
+                )
+                .await??;
+                let total_files = (sizes.len().saturating_sub(1)) as u64;
+                // The hash seq itself is downloaded and counted locally too
+                let root_size = hash_seq.into_inner().len() as u64;
+                (root_size + sizes.iter().copied().sum::<u64>(), total_files)
+            }
+            BlobFormat::Raw => {
+                let (size, _stats) = cancellable(
+                    cancel,
+                    get_verified_size(&connection, &hash_and_format.hash),
+                )
+                .await??;
+                (size, 1)
+            }
+        };
+        // Bytes left behind by an earlier attempt are not fetched again
+        let resumed_size = local.local_bytes();
 
         // Send download start progress
-        if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-            let _ = sender.send(crate::ProgressInfo {
-                operation: crate::ProgressOperation::Download,
-                current: 0,
-                total: total_size,
-                message: format!("开始下载 {} 个文件，总大小: {}", total_files, format_bytes(total_size)),
-            });
-        }
+        let stage = if resumed_size > 0 {
+            ProgressStage::DownloadResumed
+        } else {
+            ProgressStage::DownloadStarted
+        };
+        progress_sender.send(
+            ProgressInfo::new(
+                progress_sender.transfer_id(),
+                ProgressOperation::Download,
+                stage,
+                resumed_size,
+                total_size,
+            )
+            .with_file_count(total_files),
+        );
 
+        SENDME_STATE.set_transfer_state(transfer_id, TransferState::Transferring);
         let get = store.remote().execute_get(connection, local.missing());
         let mut stream = get.stream();
-        let mut last_progress = 0u64;
+        let mut last_progress = resumed_size;
+        let mut rate = RateTracker::new(resumed_size);
 
-        while let Some(item) = stream.next().await {
+        while let Some(item) = cancellable(cancel, stream.next()).await? {
             match item {
                 GetProgressItem::Progress(offset) => {
                     // Send real download progress
-                    let progress = offset as u64;
-                    if progress - last_progress >= total_size / 100 || progress == total_size { // Update every 1%
-                        if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-                            let _ = sender.send(crate::ProgressInfo {
-                                operation: crate::ProgressOperation::Download,
-                                current: progress,
-                                total: total_size,
-                                message: format!("正在下载... {}/{} ({:.1}%)",
-                                    format_bytes(progress),
-                                    format_bytes(total_size),
-                                    (progress as f64 / total_size as f64) * 100.0),
-                            });
-                        }
+                    let progress = (resumed_size + offset).min(total_size);
+                    rate.record(progress);
+                    if progress.saturating_sub(last_progress) >= total_size / 100 || progress == total_size { // Update every 1%
+                        progress_sender.send(
+                            ProgressInfo::new(
+                                progress_sender.transfer_id(),
+                                ProgressOperation::Download,
+                                ProgressStage::Downloading,
+                                progress,
+                                total_size,
+                            )
+                            .with_file_count(total_files)
+                            .with_rate(&rate)
+                            .with_connection(connection_info(endpoint, remote)),
+                        );
                         last_progress = progress;
                     }
                 }
                 GetProgressItem::Done(_) => {
                     // Send download completion progress
-                    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-                        let _ = sender.send(crate::ProgressInfo {
-                            operation: crate::ProgressOperation::Download,
-                            current: total_size,
-                            total: total_size,
-                            message: "下载完成，正在导出文件...".to_string(),
-                        });
-                    }
+                    progress_sender.send(
+                        ProgressInfo::new(
+                            progress_sender.transfer_id(),
+                            ProgressOperation::Download,
+                            ProgressStage::DownloadFinished,
+                            total_size,
+                            total_size,
+                        )
+                        .with_file_count(total_files),
+                    );
                     break;
                 }
                 GetProgressItem::Error(cause) => {
-                    anyhow::bail!("Download error: {:?}", cause);
+                    return Err(SendmeError::DownloadFailed {
+                        node_id: remote.to_string(),
+                        reason: format!("{:#}", cause),
+                    }
+                    .into());
                 }
-                _ => {}
             }
         }
-    }
+        (rate.average(), rate.peak(), connection_info(endpoint, remote))
+    } else {
+        (0, 0, None)
+    };
 
-    let collection = Collection::load(hash_and_format.hash, store.as_ref()).await?;
+    // A raw blob is exported like a collection with a single file
+    let collection = match hash_and_format.format {
+        BlobFormat::HashSeq => Collection::load(hash_and_format.hash, store)
+            .await
+            .map_err(|e| SendmeError::StoreCorrupt {
+                hash: hash_and_format.hash.to_hex().to_string(),
+                message: format!("{:#}", e),
+            })?,
+        BlobFormat::Raw => {
+            let name = options
+                .raw_file_name
+                .clone()
+                .unwrap_or_else(|| hash_and_format.hash.to_hex().to_string());
+            [(name, hash_and_format.hash)].into_iter().collect()
+        }
+    };
     let file_count = collection.len() as u64;
-    export_with_progress(&store, collection, progress_sender.clone()).await?;
+    SENDME_STATE.set_transfer_state(transfer_id, TransferState::Exporting);
+    export_with_progress(
+        store,
+        collection,
+        output_dir,
+        options.conflict_policy,
+        progress_sender.clone(),
+        cancel,
+    )
+    .await?;
 
-    // Send final completion progress
-    if let Some(sender) = progress_sender.lock().unwrap().as_ref() {
-        let _ = sender.send(crate::ProgressInfo {
-            operation: crate::ProgressOperation::Export,
-            current: 1,
-            total: 1,
-            message: "文件接收完成！".to_string(),
+    Ok(Received {
+        file_count,
+        average_bytes_per_second,
+        peak_bytes_per_second,
+        connection,
+    })
+}
+
+// The receives that are currently running by the hash they fetch, whose
+// stores must not be touched
+fn active_receives() -> HashMap<Hash, TransferInfo> {
+    SENDME_STATE
+        .active_transfers()
+        .into_iter()
+        .filter(|info| info.kind == TransferKind::Receive)
+        .filter_map(|info| {
+            let (ticket, _) = parse_blob_ticket(info.ticket.as_deref()?).ok()?;
+            Some((ticket.hash(), info))
+        })
+        .collect()
+}
+
+#[flutter_rust_bridge::frb]
+pub async fn list_partial_downloads(temp_dir: Option<String>) -> anyhow::Result<Vec<PartialDownload>> {
+    let root = match temp_dir {
+        Some(temp_dir) => PathBuf::from(temp_dir),
+        None => std::env::current_dir()?,
+    };
+    if !root.is_dir() {
+        return Ok(Vec::new());
+    }
+    let active = active_receives();
+    let mut partials = Vec::new();
+
+    let mut entries = tokio::fs::read_dir(&root).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let file_name = entry.file_name();
+        let Some(hash) = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix(RECV_DIR_PREFIX))
+            .and_then(|hex| Hash::from_str(hex).ok())
+        else {
+            continue;
+        };
+        if active.contains_key(&hash) || !entry.file_type().await?.is_dir() {
+            continue;
+        }
+
+        let path = entry.path();
+        let ticket = tokio::fs::read_to_string(path.join(RECV_TICKET_FILE)).await.ok();
+        let hash_and_format = ticket
+            .as_deref()
+            .and_then(|ticket| parse_blob_ticket(ticket).ok())
+            .map(|(ticket, _)| ticket.hash_and_format())
+            .unwrap_or(HashAndFormat::hash_seq(hash));
+
+        let store = FsStore::load(&path).await?;
+        let local = store.remote().local(hash_and_format).await?;
+        store.shutdown().await?;
+
+        partials.push(PartialDownload {
+            hash: hash.to_hex().to_string(),
+            ticket,
+            local_bytes: local.local_bytes(),
+            path: path.display().to_string(),
         });
     }
 
-    let duration = t0.elapsed();
-    tokio::fs::remove_dir_all(iroh_data_dir).await?;
+    partials.sort_by(|a, b| a.hash.cmp(&b.hash));
+    Ok(partials)
+}
 
-    // Get the local data for the received collection
-    let local_data = store.remote().local(hash_and_format).await?;
-    let result = ReceiveResult {
-        file_count,
-        size: local_data.local_bytes(),
-        duration_ms: duration.as_millis() as u64,
+#[flutter_rust_bridge::frb]
+pub async fn delete_partial_download(hash: String, temp_dir: Option<String>) -> anyhow::Result<()> {
+    let hash = Hash::from_str(&hash).context("invalid hash")?;
+    if let Some(info) = active_receives().remove(&hash) {
+        return Err(SendmeError::TransferRunning {
+            transfer_id: info.id,
+            state: info.state,
+        }
+        .into());
+    }
+    let root = match temp_dir {
+        Some(temp_dir) => PathBuf::from(temp_dir),
+        None => std::env::current_dir()?,
     };
+    let dir = recv_store_dir(&root, &hash);
+    tokio::fs::remove_dir_all(&dir)
+        .await
+        .with_context(|| format!("failed to delete {}", dir.display()))?;
+    Ok(())
+}
 
-    Ok(result)
+// Runs `fut` unless the transfer gets cancelled first
+async fn cancellable<F: Future>(cancel: &CancellationToken, fut: F) -> anyhow::Result<F::Output> {
+    tokio::select! {
+        biased;
+        _ = cancel.cancelled() => anyhow::bail!("transfer cancelled"),
+        output = fut => Ok(output),
+    }
+}
+
+#[flutter_rust_bridge::frb]
+pub fn forget_transfer(id: TransferId) -> anyhow::Result<()> {
+    let info = SENDME_STATE
+        .transfer(id)
+        .ok_or_else(|| SendmeError::UnknownTransfer {
+            id_or_ticket: id.to_string(),
+        })?;
+    if !SENDME_STATE.forget_transfer(id) {
+        return Err(SendmeError::TransferRunning {
+            transfer_id: id,
+            state: info.state,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb]
+pub async fn cancel_transfer(id: TransferId, keep_partial: bool) -> anyhow::Result<()> {
+    let info = SENDME_STATE
+        .transfer(id)
+        .ok_or_else(|| SendmeError::UnknownTransfer {
+            id_or_ticket: id.to_string(),
+        })?;
+    if info.state.is_finished() {
+        return Err(SendmeError::TransferFinished {
+            transfer_id: id,
+            state: info.state,
+        }
+        .into());
+    }
+
+    // A send that is already sharing has nothing of its own in flight, just
+    // stop it
+    if info.kind == TransferKind::Send && info.state != TransferState::Importing {
+        stop_sharing(id.to_string()).await?;
+        SENDME_STATE.set_transfer_state(id, TransferState::Cancelled);
+        return Ok(());
+    }
+
+    SENDME_STATE.cancel_transfer(id, keep_partial);
+    // Nothing runs yet, `receive_file` will refuse to start it
+    if SENDME_STATE.receive_pending(id) {
+        SENDME_STATE.set_transfer_state(id, TransferState::Cancelled);
+    }
+    Ok(())
 }
 
 // Add bytesize dependency for better formatting
@@ -459,18 +1471,442 @@ pub fn format_bytes(size: u64) -> String {
     bytesize::ByteSize::b(size).to_string()
 }
 
-// Function to validate a ticket format
+// What a ticket points to and where the sender can be reached
 #[flutter_rust_bridge::frb]
-pub fn validate_ticket(ticket: String) -> anyhow::Result<String> {
-    match BlobTicket::from_str(&ticket) {
-        Ok(parsed_ticket) => {
-            Ok(format!("Ticket 有效\n地址: {:?}\n哈希: {}\n格式: {:?}",
-                      parsed_ticket.addr(),
-                      parsed_ticket.hash().to_hex(),
-                      parsed_ticket.hash_and_format().format))
+pub fn parse_ticket(ticket: String) -> anyhow::Result<TicketInfo> {
+    let (ticket, file_name) = parse_blob_ticket(&ticket)?;
+    let addr = ticket.addr();
+    let format = match ticket.format() {
+        BlobFormat::Raw => TicketFormat::Raw,
+        BlobFormat::HashSeq => TicketFormat::HashSeq,
+    };
+    let relay_url = addr.relay_urls().next().map(|url| url.to_string());
+    let direct_addrs = addr.ip_addrs().map(|addr| addr.to_string()).collect();
+    Ok(TicketInfo {
+        node_id: addr.id.to_string(),
+        relay_url,
+        direct_addrs,
+        hash: ticket.hash().to_hex().to_string(),
+        format,
+        is_collection: format == TicketFormat::HashSeq,
+        file_name,
+    })
+}
+
+fn ticket_with_file_name(ticket: &BlobTicket, file_name: Option<&str>) -> String {
+    match file_name {
+        Some(name) => format!(
+            "{}{}{}",
+            ticket,
+            FILE_NAME_SEPARATOR,
+            utf8_percent_encode(name, FILE_NAME_ENCODE)
+        ),
+        None => ticket.to_string(),
+    }
+}
+
+// The blob ticket and, for raw blobs, the file name appended by the sender
+fn parse_blob_ticket(ticket: &str) -> anyhow::Result<(BlobTicket, Option<String>)> {
+    let invalid = |reason: String| SendmeError::InvalidTicket { reason };
+    let (ticket, file_name) = match ticket.split_once(FILE_NAME_SEPARATOR) {
+        Some((ticket, name)) => {
+            let name = percent_decode_str(name)
+                .decode_utf8()
+                .map_err(|e| invalid(format!("file name: {}", e)))?;
+            (ticket, Some(name.into_owned()).filter(|name| !name.is_empty()))
         }
-        Err(e) => anyhow::bail!("Ticket 格式无效: {}", e)
+        None => (ticket, None),
+    };
+    let ticket = BlobTicket::from_str(ticket).map_err(|e| invalid(e.to_string()))?;
+    if file_name.is_some() && ticket.format() != BlobFormat::Raw {
+        return Err(invalid("file name on a collection ticket".to_string()).into());
     }
+    Ok((ticket, file_name))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iroh::protocol::Router;
+    use iroh_blobs::{store::mem::MemStore, BlobsProtocol};
+
+    fn secret_key() -> SecretKey {
+        SecretKey::generate(&mut rand::rng())
+    }
+
+    // Creates the files, and directories for names ending in "/", below `root`
+    fn create(root: &Path, names: &[&str]) -> Vec<PathBuf> {
+        names
+            .iter()
+            .map(|name| {
+                let path = root.join(name.trim_end_matches('/'));
+                if name.ends_with('/') {
+                    std::fs::create_dir_all(&path).unwrap();
+                } else {
+                    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                    std::fs::write(&path, name.as_bytes()).unwrap();
+                }
+                path
+            })
+            .collect()
+    }
+
+    #[test]
+    fn top_level_names_are_unique() {
+        let cases: &[(&[&str], &[&str])] = &[
+            (&["1/a.txt", "2/A.txt"], &["a.txt", "A (1).txt"]),
+            (
+                &["1/x (1).txt", "1/x.txt", "2/x.txt"],
+                &["x (1).txt", "x.txt", "x (2).txt"],
+            ),
+            (&["1/.bashrc", "2/.bashrc"], &[".bashrc", ".bashrc (1)"]),
+            (
+                &["1/photos.2024/", "2/photos.2024/"],
+                &["photos.2024", "photos.2024 (1)"],
+            ),
+            (
+                &["1/archive.tar.gz", "2/archive.tar.gz"],
+                &["archive.tar.gz", "archive.tar (1).gz"],
+            ),
+            (&["1/a", "2/a/", "3/A"], &["a", "a (1)", "A (2)"]),
+        ];
+        for (picks, expected) in cases {
+            let dir = tempfile::tempdir().unwrap();
+            let sources = create(dir.path(), picks);
+            assert_eq!(top_level_names(&sources).unwrap(), *expected, "{:?}", picks);
+        }
+    }
+
+    #[test]
+    fn collected_names_are_safe() {
+        let dir = tempfile::tempdir().unwrap();
+        let picks = create(
+            dir.path(),
+            &["a.txt", "photos/x.jpg", "photos/sub/y.jpg", "other/A.TXT"],
+        );
+        let paths = [
+            picks[0].clone(),
+            dir.path().join("photos"),
+            picks[0].clone(),
+            picks[3].clone(),
+        ];
+        let mut names = collect_sources(&paths)
+            .unwrap()
+            .into_iter()
+            .map(|(name, _, _)| name)
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            ["A (1).TXT", "a.txt", "photos/sub/y.jpg", "photos/x.jpg"]
+        );
+        for name in &names {
+            safe_relative_path(name).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_relay_mode_needs_urls() {
+        let config = EndpointConfig {
+            relay_mode: RelayModeOption::Custom,
+            ..EndpointConfig::default()
+        };
+        let error = bind_endpoint(&config, secret_key(), Vec::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SendmeError>(),
+            Some(SendmeError::InvalidConfig { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn lan_only_endpoints_transfer_over_loopback() {
+        let config = EndpointConfig {
+            bind_addr: Some("127.0.0.1".to_string()),
+            ..EndpointConfig::lan_only()
+        };
+        let data = b"sent without relays".to_vec();
+
+        let provider_store = MemStore::new();
+        let hash = provider_store.add_bytes(data.clone()).await.unwrap().hash;
+        let provider = bind_endpoint(&config, secret_key(), vec![iroh_blobs::ALPN.to_vec()])
+            .await
+            .unwrap();
+        let router = Router::builder(provider)
+            .accept(iroh_blobs::ALPN, BlobsProtocol::new(&provider_store, None))
+            .spawn();
+        let mut watcher = router.endpoint().watch_addr();
+        while watcher.get().ip_addrs().next().is_none() {
+            watcher.updated().await.unwrap();
+        }
+        let ticket = BlobTicket::new(router.endpoint().addr(), hash, BlobFormat::Raw);
+        assert!(ticket.addr().relay_urls().next().is_none());
+        assert!(ticket_warning(ticket.addr(), &config).is_none());
+
+        let receiver = bind_endpoint(&config, secret_key(), Vec::new()).await.unwrap();
+        let connection = tokio::time::timeout(
+            CONNECT_TIMEOUT,
+            receiver.connect(ticket.addr().clone(), iroh_blobs::ALPN),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let store = MemStore::new();
+        store
+            .remote()
+            .fetch(connection, ticket.hash_and_format())
+            .await
+            .unwrap();
+        assert_eq!(store.get_bytes(hash).await.unwrap(), data);
+
+        receiver.close().await;
+        router.shutdown().await.unwrap();
+    }
+
+    fn collection(entries: &[(&str, &str)]) -> Collection {
+        entries
+            .iter()
+            .map(|(name, content)| (name.to_string(), Hash::new(content)))
+            .collect()
+    }
+
+    // Where each entry would be exported to, relative to `root`
+    async fn planned(
+        root: &Path,
+        entries: &[(&str, &str)],
+        policy: ConflictPolicy,
+    ) -> anyhow::Result<Vec<Option<String>>> {
+        let plan = plan_export(&collection(entries), root, policy).await?;
+        Ok(plan
+            .into_iter()
+            .map(|(_, _, target)| {
+                let target = target?;
+                Some(target.strip_prefix(root).unwrap().to_string_lossy().to_string())
+            })
+            .collect())
+    }
+
+    fn target_exists(result: anyhow::Result<Vec<Option<String>>>) -> bool {
+        matches!(
+            result.unwrap_err().downcast_ref::<SendmeError>(),
+            Some(SendmeError::TargetExists { .. })
+        )
+    }
+
+    #[tokio::test]
+    async fn case_only_clashes_are_conflicts() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let same = [("a.txt", "a"), ("A.txt", "a")];
+        let different = [("a.txt", "a"), ("A.txt", "b")];
+
+        assert!(target_exists(planned(root, &same, ConflictPolicy::Fail).await));
+        // Overwriting only applies to files that were there before
+        assert!(target_exists(planned(root, &same, ConflictPolicy::Overwrite).await));
+        assert_eq!(
+            planned(root, &same, ConflictPolicy::Skip).await.unwrap(),
+            [Some("a.txt".to_string()), None]
+        );
+        assert_eq!(
+            planned(root, &same, ConflictPolicy::RenameWithSuffix).await.unwrap(),
+            [Some("a.txt".to_string()), Some("A (1).txt".to_string())]
+        );
+        assert_eq!(
+            planned(root, &same, ConflictPolicy::SkipIfIdenticalHash).await.unwrap(),
+            [Some("a.txt".to_string()), None]
+        );
+        assert!(target_exists(
+            planned(root, &different, ConflictPolicy::SkipIfIdenticalHash).await
+        ));
+    }
+
+    #[tokio::test]
+    async fn files_block_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let entries = [("a", "a"), ("a/b", "b")];
+        for policy in [
+            ConflictPolicy::Fail,
+            ConflictPolicy::Overwrite,
+            ConflictPolicy::RenameWithSuffix,
+            ConflictPolicy::SkipIfIdenticalHash,
+        ] {
+            assert!(target_exists(planned(root, &entries, policy).await), "{:?}", policy);
+        }
+        assert_eq!(
+            planned(root, &entries, ConflictPolicy::Skip).await.unwrap(),
+            [Some("a".to_string()), None]
+        );
+
+        // Also when the file is already on disk
+        create(root, &["c"]);
+        assert!(target_exists(
+            planned(root, &[("c/d", "d")], ConflictPolicy::Overwrite).await
+        ));
+        assert_eq!(
+            planned(root, &[("c/d", "d")], ConflictPolicy::Skip).await.unwrap(),
+            [None]
+        );
+    }
+
+    #[tokio::test]
+    async fn existing_files_follow_the_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        create(root, &["x.txt", "x (1).txt"]);
+        std::fs::write(root.join("same.txt"), "same").unwrap();
+        std::fs::write(root.join("diff.txt"), "old").unwrap();
+
+        let entries = [("x.txt", "x")];
+        assert!(target_exists(planned(root, &entries, ConflictPolicy::Fail).await));
+        assert_eq!(
+            planned(root, &entries, ConflictPolicy::Overwrite).await.unwrap(),
+            [Some("x.txt".to_string())]
+        );
+        assert_eq!(
+            planned(root, &entries, ConflictPolicy::Skip).await.unwrap(),
+            [None]
+        );
+
+        // Suffixes skip both files on disk and ones claimed by earlier entries
+        assert_eq!(
+            planned(
+                root,
+                &[("x.txt", "x"), ("x (2).txt", "y")],
+                ConflictPolicy::RenameWithSuffix
+            )
+            .await
+            .unwrap(),
+            [Some("x (2).txt".to_string()), Some("x (2) (1).txt".to_string())]
+        );
+
+        assert_eq!(
+            planned(root, &[("same.txt", "same")], ConflictPolicy::SkipIfIdenticalHash)
+                .await
+                .unwrap(),
+            [None]
+        );
+        assert!(target_exists(
+            planned(root, &[("diff.txt", "new")], ConflictPolicy::SkipIfIdenticalHash).await
+        ));
+    }
+
+    #[tokio::test]
+    async fn failed_plans_write_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(root.join("b.txt"), "old").unwrap();
+        let store = MemStore::new();
+
+        let error = export_with_progress(
+            &store,
+            collection(&[("a.txt", "a"), ("b.txt", "b")]),
+            root,
+            ConflictPolicy::Fail,
+            SENDME_STATE.progress_sender(0),
+            &CancellationToken::new(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SendmeError>(),
+            Some(SendmeError::TargetExists { .. })
+        ));
+        let entries = std::fs::read_dir(root)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(entries, ["b.txt"]);
+        assert_eq!(std::fs::read_to_string(root.join("b.txt")).unwrap(), "old");
+    }
+
+    #[tokio::test]
+    async fn partial_downloads_are_listed_until_resumed() {
+        let dir = tempfile::tempdir().unwrap();
+        let temp_dir = Some(dir.path().to_string_lossy().to_string());
+
+        // A receive that got the collection and one of its two files
+        let present = b"downloaded".to_vec();
+        let collection = Collection::from_iter([
+            ("a", Hash::new(&present)),
+            ("b", Hash::new(b"still missing")),
+        ]);
+        let store_dir = dir.path().join("store");
+        let store = FsStore::load(&store_dir).await.unwrap();
+        store.add_bytes(present.clone()).await.unwrap();
+        let hash = collection.store(&store).await.unwrap().hash();
+        let root = store.get_bytes(hash).await.unwrap();
+        let meta = Hash::from_bytes(root[..32].try_into().unwrap());
+        let meta_len = store.get_bytes(meta).await.unwrap().len();
+        let local_bytes = (root.len() + meta_len + present.len()) as u64;
+        store.shutdown().await.unwrap();
+        let path = recv_store_dir(dir.path(), &hash);
+        std::fs::rename(&store_dir, &path).unwrap();
 
+        let addr = EndpointAddr::new(secret_key().public());
+        let ticket = BlobTicket::new(addr, hash, BlobFormat::HashSeq).to_string();
+        std::fs::write(path.join(RECV_TICKET_FILE), &ticket).unwrap();
+
+        let partials = list_partial_downloads(temp_dir.clone()).await.unwrap();
+        assert_eq!(partials.len(), 1);
+        assert_eq!(partials[0].hash, hash.to_hex().to_string());
+        assert_eq!(partials[0].ticket.as_deref(), Some(ticket.as_str()));
+        assert_eq!(partials[0].local_bytes, local_bytes);
+        assert_eq!(partials[0].path, path.display().to_string());
+
+        // Resuming it hides it and protects it from deletion
+        let id = SENDME_STATE.start_transfer(TransferKind::Receive);
+        SENDME_STATE.set_transfer_ticket(id, ticket);
+        assert!(list_partial_downloads(temp_dir.clone()).await.unwrap().is_empty());
+        let error = delete_partial_download(hash.to_hex().to_string(), temp_dir.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SendmeError>(),
+            Some(SendmeError::TransferRunning { transfer_id, .. }) if *transfer_id == id
+        ));
+        assert!(path.is_dir());
+
+        SENDME_STATE.set_transfer_state(id, TransferState::Failed);
+        assert_eq!(list_partial_downloads(temp_dir.clone()).await.unwrap().len(), 1);
+        delete_partial_download(hash.to_hex().to_string(), temp_dir.clone())
+            .await
+            .unwrap();
+        assert!(!path.exists());
+        assert!(list_partial_downloads(temp_dir).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn raw_ticket_carries_file_name() {
+        let addr = EndpointAddr::new(secret_key().public());
+        let hash = Hash::new(b"raw");
+        let raw = BlobTicket::new(addr.clone(), hash, BlobFormat::Raw);
+
+        let name = "report #1 (final) é.pdf";
+        let ticket = ticket_with_file_name(&raw, Some(name));
+        assert!(ticket.starts_with(&format!("{}#", raw)));
+        let (parsed, file_name) = parse_blob_ticket(&ticket).unwrap();
+        assert_eq!(parsed, raw);
+        assert_eq!(file_name.as_deref(), Some(name));
+
+        let (_, file_name) = parse_blob_ticket(&raw.to_string()).unwrap();
+        assert_eq!(file_name, None);
+
+        // The name the send path gives a single picked file
+        let dir = tempfile::tempdir().unwrap();
+        let picked = create(dir.path(), &[name]);
+        let (sent_name, _, _) = collect_sources(&picked).unwrap().remove(0);
+        let ticket = ticket_with_file_name(&raw, Some(&sent_name));
+        let (_, file_name) = parse_blob_ticket(&ticket).unwrap();
+        assert_eq!(file_name.as_deref(), Some(name));
+        safe_relative_path(&sent_name).unwrap();
+
+        let collection = BlobTicket::new(addr, hash, BlobFormat::HashSeq);
+        let error = parse_blob_ticket(&format!("{}#name", collection)).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SendmeError>(),
+            Some(SendmeError::InvalidTicket { .. })
+        ));
+    }
+}
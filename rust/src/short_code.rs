@@ -0,0 +1,283 @@
+use crate::{error::SendmeError, CodeResolverConfig, TransferId, TransferKind, SENDME_STATE};
+use n0_future::boxed::BoxFuture;
+use rand::Rng;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::{Arc, Mutex},
+};
+
+// Letters and digits that cannot be mistaken for each other when read out
+// or typed on a TV remote
+const ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+const CODE_LEN: usize = 8;
+// How often a new code is drawn when the resolver already knows the last one
+const MAX_ATTEMPTS: usize = 8;
+
+// Maps short codes to tickets. Codes are passed in their normalized form.
+pub trait CodeResolver: Send + Sync {
+    // Returns false if the code is already taken
+    fn publish(&self, code: String, ticket: String) -> BoxFuture<anyhow::Result<bool>>;
+    fn resolve(&self, code: String) -> BoxFuture<anyhow::Result<Option<String>>>;
+    fn unpublish(&self, code: String) -> BoxFuture<anyhow::Result<()>>;
+}
+
+// Codes that only resolve within this app instance
+#[derive(Default)]
+pub struct InProcessResolver {
+    codes: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl CodeResolver for InProcessResolver {
+    fn publish(&self, code: String, ticket: String) -> BoxFuture<anyhow::Result<bool>> {
+        let mut codes = self.codes.lock().unwrap();
+        let published = match codes.entry(code) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(ticket);
+                true
+            }
+        };
+        Box::pin(async move { Ok(published) })
+    }
+
+    fn resolve(&self, code: String) -> BoxFuture<anyhow::Result<Option<String>>> {
+        let ticket = self.codes.lock().unwrap().get(&code).cloned();
+        Box::pin(async move { Ok(ticket) })
+    }
+
+    fn unpublish(&self, code: String) -> BoxFuture<anyhow::Result<()>> {
+        self.codes.lock().unwrap().remove(&code);
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+// A rendezvous server that stores tickets as plain text under
+// `{base_url}/{code}`: PUT with `If-None-Match: *` publishes, GET resolves and
+// DELETE unpublishes.
+pub struct HttpResolver {
+    client: reqwest::Client,
+    base_url: reqwest::Url,
+}
+
+impl HttpResolver {
+    pub fn new(base_url: &str) -> anyhow::Result<Self> {
+        let invalid = |reason: String| SendmeError::InvalidConfig { reason };
+        let mut base_url = reqwest::Url::parse(base_url)
+            .map_err(|e| invalid(format!("resolver URL {}: {}", base_url, e)))?;
+        if base_url.cannot_be_a_base() {
+            return Err(invalid(format!("resolver URL {} cannot have paths", base_url)).into());
+        }
+        // Without the slash `join` would replace the last path segment
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        Ok(Self {
+            client: reqwest::Client::new(),
+            base_url,
+        })
+    }
+
+    fn url(&self, code: &str) -> anyhow::Result<reqwest::Url> {
+        Ok(self.base_url.join(code)?)
+    }
+}
+
+impl CodeResolver for HttpResolver {
+    fn publish(&self, code: String, ticket: String) -> BoxFuture<anyhow::Result<bool>> {
+        let request = self
+            .url(&code)
+            .map(|url| self.client.put(url).header("If-None-Match", "*").body(ticket));
+        Box::pin(async move {
+            let response = request?.send().await?;
+            match response.status() {
+                reqwest::StatusCode::CONFLICT | reqwest::StatusCode::PRECONDITION_FAILED => {
+                    Ok(false)
+                }
+                _ => {
+                    response.error_for_status()?;
+                    Ok(true)
+                }
+            }
+        })
+    }
+
+    fn resolve(&self, code: String) -> BoxFuture<anyhow::Result<Option<String>>> {
+        let request = self.url(&code).map(|url| self.client.get(url));
+        Box::pin(async move {
+            let response = request?.send().await?;
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            let ticket = response.error_for_status()?.text().await?;
+            Ok(Some(ticket.trim().to_string()))
+        })
+    }
+
+    fn unpublish(&self, code: String) -> BoxFuture<anyhow::Result<()>> {
+        let request = self.url(&code).map(|url| self.client.delete(url));
+        Box::pin(async move {
+            let response = request?.send().await?;
+            if response.status() != reqwest::StatusCode::NOT_FOUND {
+                response.error_for_status()?;
+            }
+            Ok(())
+        })
+    }
+}
+
+pub fn set_code_resolver(config: CodeResolverConfig) -> anyhow::Result<()> {
+    let resolver: Arc<dyn CodeResolver> = match config {
+        CodeResolverConfig::InProcess => Arc::new(InProcessResolver::default()),
+        CodeResolverConfig::Http { base_url } => Arc::new(HttpResolver::new(&base_url)?),
+    };
+    SENDME_STATE.set_code_resolver(resolver);
+    Ok(())
+}
+
+// Upper case without separators, so "abcd-efgh" and "ABCD EFGH" are the same
+fn normalize(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+fn is_code(normalized: &str) -> bool {
+    normalized.len() == CODE_LEN && normalized.bytes().all(|b| ALPHABET.contains(&b))
+}
+
+// Split in two halves for reading out
+fn display(code: &str) -> String {
+    let (head, tail) = code.split_at(CODE_LEN / 2);
+    format!("{}-{}", head, tail)
+}
+
+fn random_code() -> String {
+    let mut rng = rand::rng();
+    (0..CODE_LEN)
+        .map(|_| ALPHABET[rng.random_range(0..ALPHABET.len())] as char)
+        .collect()
+}
+
+// Publishes the ticket of a running send under a new code. A send keeps its
+// code until it stops sharing.
+pub async fn publish_short_code(transfer_id: TransferId) -> anyhow::Result<String> {
+    let unknown = || SendmeError::UnknownTransfer {
+        id_or_ticket: transfer_id.to_string(),
+    };
+    let info = SENDME_STATE.transfer(transfer_id).ok_or_else(unknown)?;
+    if let Some(code) = &info.short_code {
+        return Ok(display(code));
+    }
+    let ticket = match info.ticket {
+        Some(ticket) if info.kind == TransferKind::Send && !info.state.is_finished() => ticket,
+        _ => return Err(unknown().into()),
+    };
+
+    let resolver = SENDME_STATE.code_resolver();
+    for _ in 0..MAX_ATTEMPTS {
+        let code = random_code();
+        if resolver.publish(code.clone(), ticket.clone()).await? {
+            SENDME_STATE.set_short_code(transfer_id, code.clone());
+            return Ok(display(&code));
+        }
+    }
+    Err(SendmeError::NoFreeShortCode {
+        attempts: MAX_ATTEMPTS as u32,
+    }
+    .into())
+}
+
+// Best effort, the resolver may be unreachable by now
+pub async fn unpublish_short_code(code: String) {
+    if let Err(e) = SENDME_STATE.code_resolver().unpublish(code).await {
+        tracing::warn!("failed to unpublish short code: {:#}", e);
+    }
+}
+
+pub async fn resolve_short_code(code: String) -> anyhow::Result<String> {
+    let normalized = normalize(&code);
+    if !is_code(&normalized) {
+        return Err(SendmeError::UnknownCode { code }.into());
+    }
+    SENDME_STATE
+        .code_resolver()
+        .resolve(normalized)
+        .await?
+        .ok_or_else(|| SendmeError::UnknownCode { code }.into())
+}
+
+// Lets the receiver enter either a full ticket or a short code
+pub async fn resolve_ticket(ticket_or_code: String) -> anyhow::Result<String> {
+    if is_code(&normalize(&ticket_or_code)) {
+        resolve_short_code(ticket_or_code).await
+    } else {
+        Ok(ticket_or_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iroh::{EndpointAddr, SecretKey, TransportAddr};
+    use iroh_blobs::{ticket::BlobTicket, BlobFormat, Hash};
+
+    fn ticket() -> String {
+        let id = SecretKey::generate(&mut rand::rng()).public();
+        let addr = EndpointAddr::from_parts(id, Vec::<TransportAddr>::new());
+        BlobTicket::new(addr, Hash::new(b"data"), BlobFormat::Raw).to_string()
+    }
+
+    #[test]
+    fn codes_are_normalized() {
+        assert_eq!(normalize("abcd-efgh"), "ABCDEFGH");
+        assert_eq!(normalize(" ABCD efgh "), "ABCDEFGH");
+        assert!(is_code("ABCDEFGH"));
+        assert!(is_code("23456789"));
+        // Letters and digits that look alike are not part of the alphabet
+        assert!(!is_code("ABCDEFGI"));
+        assert!(!is_code("ABCD0FGH"));
+        assert!(!is_code("ABCDEFG"));
+        assert!(!is_code("ABCDEFGHJ"));
+        assert!(!is_code(&normalize(&ticket())));
+
+        let code = random_code();
+        assert!(is_code(&code));
+        assert_eq!(normalize(&display(&code)), code);
+    }
+
+    #[tokio::test]
+    async fn in_process_resolver_round_trip() {
+        let resolver = InProcessResolver::default();
+        let ticket = ticket();
+        let code = random_code();
+
+        assert!(resolver.publish(code.clone(), ticket.clone()).await.unwrap());
+        assert!(!resolver.publish(code.clone(), "other".to_string()).await.unwrap());
+        assert_eq!(resolver.resolve(code.clone()).await.unwrap(), Some(ticket));
+        resolver.unpublish(code.clone()).await.unwrap();
+        assert_eq!(resolver.resolve(code).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn resolve_ticket_accepts_tickets_and_codes() {
+        let ticket = ticket();
+        assert_eq!(resolve_ticket(ticket.clone()).await.unwrap(), ticket);
+
+        let code = random_code();
+        SENDME_STATE
+            .code_resolver()
+            .publish(code.clone(), ticket.clone())
+            .await
+            .unwrap();
+        let typed = display(&code).to_lowercase();
+        assert_eq!(resolve_ticket(typed).await.unwrap(), ticket);
+
+        SENDME_STATE.code_resolver().unpublish(code.clone()).await.unwrap();
+        let error = resolve_ticket(code).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SendmeError>(),
+            Some(SendmeError::UnknownCode { .. })
+        ));
+    }
+}